@@ -0,0 +1,285 @@
+use bevy::prelude::*;
+
+use crate::events::{BoardSide, ShotResolved, ShotResult};
+use crate::{CellState, GameState, PlacedShip, PlacementMode, GRID_SIZE};
+
+/// A computer opponent that fires at the player's board once per player turn,
+/// using the same hunt/target heatmap approach as the opponent-board advisor.
+#[derive(Resource, Default)]
+pub struct AiState {
+    pub enabled: bool,
+    opponent_shots_seen: usize,
+}
+
+impl AiState {
+    /// Forgets the shot high-water mark so a fresh board (new game, reset,
+    /// or load) doesn't leave the AI thinking shots it hasn't seen yet are
+    /// ones it already fired, which would otherwise stall it until the
+    /// player re-accumulates past the stale count.
+    pub fn reset(&mut self) {
+        self.opponent_shots_seen = 0;
+    }
+}
+
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+fn ship_cells(ship: &PlacedShip) -> Vec<(usize, usize)> {
+    (0..ship.ship_type.size())
+        .map(|i| {
+            if ship.is_horizontal {
+                (ship.x + i, ship.y)
+            } else {
+                (ship.x, ship.y + i)
+            }
+        })
+        .collect()
+}
+
+fn remaining_ship_sizes(board: &[[CellState; GRID_SIZE]; GRID_SIZE], ships: &[PlacedShip]) -> Vec<usize> {
+    ships
+        .iter()
+        .filter(|ship| {
+            !ship_cells(ship)
+                .iter()
+                .all(|&(x, y)| board[y][x] == CellState::Hit)
+        })
+        .map(|ship| ship.ship_type.size())
+        .collect()
+}
+
+/// Hits that belong to a ship which isn't fully sunk yet.
+fn unresolved_hit_cells(board: &[[CellState; GRID_SIZE]; GRID_SIZE], ships: &[PlacedShip]) -> Vec<(usize, usize)> {
+    let mut hits = Vec::new();
+    for ship in ships {
+        let cells = ship_cells(ship);
+        let ship_hits: Vec<(usize, usize)> = cells
+            .iter()
+            .copied()
+            .filter(|&(x, y)| board[y][x] == CellState::Hit)
+            .collect();
+        if !ship_hits.is_empty() && ship_hits.len() < cells.len() {
+            hits.extend(ship_hits);
+        }
+    }
+    hits
+}
+
+/// Counts, for every empty cell, how many legal remaining-fleet placements cover it.
+fn build_hunt_heatmap(board: &[[CellState; GRID_SIZE]; GRID_SIZE], remaining_sizes: &[usize]) -> [[u32; GRID_SIZE]; GRID_SIZE] {
+    let mut heat = [[0u32; GRID_SIZE]; GRID_SIZE];
+
+    for &size in remaining_sizes {
+        for y in 0..GRID_SIZE {
+            for x in 0..=GRID_SIZE.saturating_sub(size) {
+                if (0..size).all(|i| board[y][x + i] == CellState::Empty) {
+                    for i in 0..size {
+                        heat[y][x + i] += 1;
+                    }
+                }
+            }
+        }
+
+        for x in 0..GRID_SIZE {
+            for y in 0..=GRID_SIZE.saturating_sub(size) {
+                if (0..size).all(|i| board[y + i][x] == CellState::Empty) {
+                    for i in 0..size {
+                        heat[y + i][x] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    heat
+}
+
+fn pick_best_candidate(
+    board: &[[CellState; GRID_SIZE]; GRID_SIZE],
+    heat: &[[u32; GRID_SIZE]; GRID_SIZE],
+    parity: Option<usize>,
+) -> Option<(usize, usize)> {
+    let mut best: Option<((usize, usize), u32)> = None;
+    for y in 0..GRID_SIZE {
+        for x in 0..GRID_SIZE {
+            if board[y][x] != CellState::Empty {
+                continue;
+            }
+            if let Some(parity) = parity {
+                if parity == 0 || (x + y) % parity != 0 {
+                    continue;
+                }
+            }
+            let score = heat[y][x];
+            if best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some(((x, y), score));
+            }
+        }
+    }
+    best.map(|(cell, _)| cell)
+}
+
+fn colinear_axis(hits: &[(usize, usize)]) -> Option<(Axis, Vec<(usize, usize)>)> {
+    if hits.len() < 2 {
+        return None;
+    }
+
+    if hits.iter().all(|&(_, y)| y == hits[0].1) {
+        let mut cells = hits.to_vec();
+        cells.sort_by_key(|&(x, _)| x);
+        Some((Axis::Horizontal, cells))
+    } else if hits.iter().all(|&(x, _)| x == hits[0].0) {
+        let mut cells = hits.to_vec();
+        cells.sort_by_key(|&(_, y)| y);
+        Some((Axis::Vertical, cells))
+    } else {
+        None
+    }
+}
+
+fn line_end_candidates(
+    board: &[[CellState; GRID_SIZE]; GRID_SIZE],
+    axis: Axis,
+    cells: &[(usize, usize)],
+) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    match axis {
+        Axis::Horizontal => {
+            let y = cells[0].1;
+            let min_x = cells.first().unwrap().0;
+            let max_x = cells.last().unwrap().0;
+            if min_x > 0 && board[y][min_x - 1] == CellState::Empty {
+                out.push((min_x - 1, y));
+            }
+            if max_x + 1 < GRID_SIZE && board[y][max_x + 1] == CellState::Empty {
+                out.push((max_x + 1, y));
+            }
+        }
+        Axis::Vertical => {
+            let x = cells[0].0;
+            let min_y = cells.first().unwrap().1;
+            let max_y = cells.last().unwrap().1;
+            if min_y > 0 && board[min_y - 1][x] == CellState::Empty {
+                out.push((x, min_y - 1));
+            }
+            if max_y + 1 < GRID_SIZE && board[max_y + 1][x] == CellState::Empty {
+                out.push((x, max_y + 1));
+            }
+        }
+    }
+    out
+}
+
+fn adjacent_candidates(board: &[[CellState; GRID_SIZE]; GRID_SIZE], hits: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut seen = std::collections::HashSet::new();
+    let mut out = Vec::new();
+
+    for &(x, y) in hits {
+        let neighbors = [
+            (x.checked_sub(1), Some(y)),
+            (x.checked_add(1).filter(|&v| v < GRID_SIZE), Some(y)),
+            (Some(x), y.checked_sub(1)),
+            (Some(x), y.checked_add(1).filter(|&v| v < GRID_SIZE)),
+        ];
+        for (nx, ny) in neighbors {
+            if let (Some(nx), Some(ny)) = (nx, ny) {
+                if board[ny][nx] == CellState::Empty && seen.insert((nx, ny)) {
+                    out.push((nx, ny));
+                }
+            }
+        }
+    }
+
+    out
+}
+
+fn target_mode_shot(
+    board: &[[CellState; GRID_SIZE]; GRID_SIZE],
+    hits: &[(usize, usize)],
+    heat: &[[u32; GRID_SIZE]; GRID_SIZE],
+) -> Option<(usize, usize)> {
+    let candidates = match colinear_axis(hits) {
+        Some((axis, cells)) => line_end_candidates(board, axis, &cells),
+        None => adjacent_candidates(board, hits),
+    };
+
+    candidates.into_iter().max_by_key(|&(x, y)| heat[y][x])
+}
+
+/// Picks the AI's next shot against `board`. The layout of `ships` is used
+/// only to tell which hits belong to an already-sunk ship and which sizes are
+/// still afloat; un-hit `Ship` cells are treated as un-fired water. Falls back
+/// to a pure heatmap pick if target mode finds no legal adjacent candidate.
+fn next_ai_shot(board: &[[CellState; GRID_SIZE]; GRID_SIZE], ships: &[PlacedShip]) -> Option<(usize, usize)> {
+    let remaining_sizes = remaining_ship_sizes(board, ships);
+    if remaining_sizes.is_empty() {
+        return None;
+    }
+    let mut unfired = *board;
+    for cell in unfired.iter_mut().flatten() {
+        if *cell == CellState::Ship {
+            *cell = CellState::Empty;
+        }
+    }
+    let board = &unfired;
+    let smallest = *remaining_sizes.iter().min().unwrap();
+    let heat = build_hunt_heatmap(board, &remaining_sizes);
+
+    let unresolved = unresolved_hit_cells(board, ships);
+    if !unresolved.is_empty() {
+        if let Some(shot) = target_mode_shot(board, &unresolved, &heat) {
+            return Some(shot);
+        }
+    }
+
+    pick_best_candidate(board, &heat, Some(smallest)).or_else(|| pick_best_candidate(board, &heat, None))
+}
+
+fn shots_fired(board: &[[CellState; GRID_SIZE]; GRID_SIZE]) -> usize {
+    board
+        .iter()
+        .flatten()
+        .filter(|&&state| state == CellState::Hit || state == CellState::Miss)
+        .count()
+}
+
+/// Fires one AI shot at `player_board` each time the player fires one at
+/// `opponent_board`, so a solo game is fully playable without a network server.
+pub fn ai_take_turn(
+    game_state: Res<GameState>,
+    mut ai_state: ResMut<AiState>,
+    mut shot_writer: EventWriter<ShotResolved>,
+) {
+    if !ai_state.enabled || !matches!(game_state.placement_mode, PlacementMode::Playing) {
+        return;
+    }
+
+    let opponent_shots = shots_fired(&game_state.opponent_board);
+    if opponent_shots <= ai_state.opponent_shots_seen {
+        return;
+    }
+    ai_state.opponent_shots_seen = opponent_shots;
+
+    let Some((x, y)) = next_ai_shot(&game_state.player_board, &game_state.ship_positions) else {
+        return;
+    };
+
+    let hit = game_state.player_board[y][x] == CellState::Ship;
+
+    shot_writer.write(ShotResolved {
+        board: BoardSide::Player,
+        x,
+        y,
+        result: if hit { ShotResult::Hit } else { ShotResult::Miss },
+    });
+
+    println!(
+        "AI fires at {}, {}: {}",
+        x,
+        y,
+        if hit { "Hit!" } else { "Miss." }
+    );
+}
+
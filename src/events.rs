@@ -0,0 +1,306 @@
+use bevy::prelude::*;
+
+use crate::hotseat::HotseatState;
+use crate::particles::{self, ImpactEffects, ImpactKind};
+use crate::{
+    cell_world_pos, play_sound_event, resolve_sunk_hits, CellState, GameSettings, GameState,
+    PlacementMode, ShipType, SoundAssets, SoundEvent, GRID_SIZE, STANDARD_FLEET_SIZES,
+};
+
+/// Which board a shot/sinking/game-over event concerns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardSide {
+    Player,
+    Opponent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShotResult {
+    Hit,
+    Miss,
+}
+
+/// Raised by the keyboard, network, and AI systems instead of writing
+/// `CellState` and calling `play_sound_event` directly, so board mutation,
+/// particles, sound, and status text all flow through one pipeline no
+/// matter which of the three fires the shot.
+#[derive(Event, Clone, Copy)]
+pub struct ShotResolved {
+    pub board: BoardSide,
+    pub x: usize,
+    pub y: usize,
+    pub result: ShotResult,
+}
+
+/// Raised once every cell of a ship on the player's board is `Hit`. Sinkings
+/// on the opponent's board (attributable to a ship only in hotseat) are
+/// announced directly by `apply_shot_resolved` and do not raise this event.
+#[derive(Event, Clone, Copy)]
+pub struct ShipSunk {
+    pub ship_type: ShipType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winner {
+    Player,
+    Opponent,
+}
+
+#[derive(Event, Clone, Copy)]
+pub struct GameOver {
+    pub winner: Winner,
+}
+
+/// Returns the ship covering `(x, y)` on the player's board if that hit just
+/// completed it.
+fn newly_sunk_ship(game_state: &GameState, x: usize, y: usize) -> Option<ShipType> {
+    let cells = game_state.get_ship_cells(x, y)?;
+    let fully_hit = cells
+        .iter()
+        .all(|&(sx, sy)| game_state.player_board[sy][sx] == CellState::Hit);
+    fully_hit.then(|| game_state.get_ship_at(x, y)).flatten()
+}
+
+/// Whether every ship the player placed has all of its cells `Hit`.
+fn all_player_ships_sunk(game_state: &GameState) -> bool {
+    !game_state.ship_positions.is_empty()
+        && game_state.ship_positions.iter().all(|ship| {
+            game_state
+                .get_ship_cells(ship.x, ship.y)
+                .map(|cells| {
+                    cells
+                        .iter()
+                        .all(|&(sx, sy)| game_state.player_board[sy][sx] == CellState::Hit)
+                })
+                .unwrap_or(false)
+        })
+}
+
+/// Whether the opponent's board, whose real layout is never known to this
+/// client, has accounted for every standard fleet size via `resolve_sunk_hits`.
+fn all_opponent_ships_sunk(board: &[[CellState; GRID_SIZE]; GRID_SIZE]) -> bool {
+    let (remaining, _claimed) = resolve_sunk_hits(board);
+    let hits = board
+        .iter()
+        .flatten()
+        .filter(|&&cell| cell == CellState::Hit)
+        .count();
+    remaining.is_empty() && hits == STANDARD_FLEET_SIZES.iter().sum::<usize>()
+}
+
+/// Returns the ship covering `(x, y)` on Player Two's (opponent) board if
+/// that hit just completed it. Only meaningful in hotseat, where the
+/// opponent's fleet is real instead of heuristically inferred.
+fn newly_sunk_opponent_ship(game_state: &GameState, x: usize, y: usize) -> Option<ShipType> {
+    let cells = game_state.get_opponent_ship_cells(x, y)?;
+    let fully_hit = cells
+        .iter()
+        .all(|&(sx, sy)| game_state.opponent_board[sy][sx] == CellState::Hit);
+    fully_hit.then(|| game_state.get_opponent_ship_at(x, y)).flatten()
+}
+
+/// Whether every ship Player Two placed has all of its cells `Hit`. Only
+/// meaningful in hotseat.
+fn all_opponent_fleet_sunk_exact(game_state: &GameState) -> bool {
+    !game_state.opponent_ship_positions.is_empty()
+        && game_state.opponent_ship_positions.iter().all(|ship| {
+            game_state
+                .get_opponent_ship_cells(ship.x, ship.y)
+                .map(|cells| {
+                    cells
+                        .iter()
+                        .all(|&(sx, sy)| game_state.opponent_board[sy][sx] == CellState::Hit)
+                })
+                .unwrap_or(false)
+        })
+}
+
+/// Applies a resolved shot to the right board, spawns impact/sunk particles,
+/// plays the matching sound, and raises `ShipSunk`/`GameOver` when a hit
+/// completes a ship or a whole fleet.
+pub fn apply_shot_resolved(
+    mut shots: EventReader<ShotResolved>,
+    mut game_state: ResMut<GameState>,
+    mut sunk_writer: EventWriter<ShipSunk>,
+    mut game_over_writer: EventWriter<GameOver>,
+    mut settings: ResMut<GameSettings>,
+    sounds: Option<Res<SoundAssets>>,
+    impact_effects: Option<Res<ImpactEffects>>,
+    hotseat: Res<HotseatState>,
+    mut commands: Commands,
+) {
+    for shot in shots.read() {
+        // Clear out the previous shot's sunk-ship announcement so it doesn't
+        // freeze the status line forever; this shot sets a fresh one below
+        // if (and only if) it sinks something.
+        game_state.announcement = None;
+
+        let is_player_board = shot.board == BoardSide::Player;
+        let board = if is_player_board {
+            &mut game_state.player_board
+        } else {
+            &mut game_state.opponent_board
+        };
+        let was_unresolved = board[shot.y][shot.x] == CellState::Empty;
+        board[shot.y][shot.x] = match shot.result {
+            ShotResult::Hit => CellState::Hit,
+            ShotResult::Miss => CellState::Miss,
+        };
+
+        // Only shots fired at the opponent's board count toward the player's
+        // own lifetime accuracy; incoming fire isn't the player's shooting.
+        // `was_unresolved` guards against re-marking an already-resolved cell
+        // inflating the tally (belt-and-suspenders with the emitters, which
+        // should already only fire on an `Empty` cell). Hotseat is excluded
+        // entirely: `BoardSide` there means "whose board", not "whose shot",
+        // so Player One's outgoing fire lands as `Opponent` and Player Two's
+        // as `Player` - neither maps to "this single saved profile's shooting"
+        // the way solo/network play does, and only half of a hotseat match's
+        // shots would otherwise be counted.
+        if !is_player_board && was_unresolved && !hotseat.enabled {
+            match shot.result {
+                ShotResult::Hit => settings.total_hits += 1,
+                ShotResult::Miss => settings.total_misses += 1,
+            }
+            settings.save();
+        }
+
+        {
+            let kind = match shot.result {
+                ShotResult::Hit => ImpactKind::Hit,
+                ShotResult::Miss => ImpactKind::Miss,
+            };
+            if let Some(ref impact_effects) = impact_effects {
+                particles::spawn_impact_effect(
+                    &mut commands,
+                    impact_effects,
+                    &settings,
+                    kind,
+                    cell_world_pos(is_player_board, shot.x, shot.y),
+                );
+            } else {
+                particles::spawn_cpu_impact_particles(
+                    &mut commands,
+                    &settings,
+                    kind,
+                    cell_world_pos(is_player_board, shot.x, shot.y),
+                );
+            }
+        }
+
+        // In hotseat both fleets are real, so a hit on the opponent's board
+        // can be attributed to a specific ship exactly, same as the player's
+        // own fleet; outside hotseat the opponent's layout stays unknown.
+        let sunk_opponent_ship = (hotseat.enabled && !is_player_board && shot.result == ShotResult::Hit)
+            .then(|| newly_sunk_opponent_ship(&game_state, shot.x, shot.y))
+            .flatten();
+
+        let sunk_ship = (is_player_board && shot.result == ShotResult::Hit)
+            .then(|| newly_sunk_ship(&game_state, shot.x, shot.y))
+            .flatten();
+
+        if let Some(ship_type) = sunk_opponent_ship {
+            if let Some(ref impact_effects) = impact_effects {
+                particles::spawn_impact_effect(
+                    &mut commands,
+                    impact_effects,
+                    &settings,
+                    ImpactKind::Sunk,
+                    cell_world_pos(false, shot.x, shot.y),
+                );
+            } else {
+                particles::spawn_cpu_impact_particles(
+                    &mut commands,
+                    &settings,
+                    ImpactKind::Sunk,
+                    cell_world_pos(false, shot.x, shot.y),
+                );
+            }
+            game_state.announcement = Some(format!("You sank the enemy {}!", ship_type.name()));
+            if let Some(ref sounds) = sounds {
+                play_sound_event(&mut commands, sounds, &settings, SoundEvent::Sunk);
+            }
+            if all_opponent_fleet_sunk_exact(&game_state) {
+                game_over_writer.write(GameOver { winner: Winner::Player });
+            }
+        } else if let Some(ship_type) = sunk_ship {
+            if let Some(ref impact_effects) = impact_effects {
+                particles::spawn_impact_effect(
+                    &mut commands,
+                    impact_effects,
+                    &settings,
+                    ImpactKind::Sunk,
+                    cell_world_pos(true, shot.x, shot.y),
+                );
+            } else {
+                particles::spawn_cpu_impact_particles(
+                    &mut commands,
+                    &settings,
+                    ImpactKind::Sunk,
+                    cell_world_pos(true, shot.x, shot.y),
+                );
+            }
+            sunk_writer.write(ShipSunk { ship_type });
+        } else if let Some(ref sounds) = sounds {
+            let sound_event = match shot.result {
+                ShotResult::Hit => SoundEvent::Hit,
+                ShotResult::Miss => SoundEvent::Miss,
+            };
+            play_sound_event(&mut commands, sounds, &settings, sound_event);
+        }
+
+        // Outside hotseat, the opponent's real layout is unknown, so sinking
+        // the whole fleet can only be inferred heuristically.
+        if !hotseat.enabled
+            && !is_player_board
+            && shot.result == ShotResult::Hit
+            && all_opponent_ships_sunk(&game_state.opponent_board)
+        {
+            game_over_writer.write(GameOver { winner: Winner::Player });
+        }
+    }
+}
+
+/// Surfaces the sunk-ship announcement, plays the sunk clip, and raises
+/// `GameOver` once the player's whole fleet is gone.
+pub fn apply_ship_sunk(
+    mut events: EventReader<ShipSunk>,
+    mut game_state: ResMut<GameState>,
+    mut game_over_writer: EventWriter<GameOver>,
+    settings: Res<GameSettings>,
+    sounds: Option<Res<SoundAssets>>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        game_state.announcement = Some(format!("Your {} was sunk!", event.ship_type.name()));
+        if let Some(ref sounds) = sounds {
+            play_sound_event(&mut commands, sounds, &settings, SoundEvent::Sunk);
+        }
+
+        if all_player_ships_sunk(&game_state) {
+            game_over_writer.write(GameOver { winner: Winner::Opponent });
+        }
+    }
+}
+
+/// Surfaces the match result, transitions into `PlacementMode::GameOver`,
+/// and tallies the win/loss into the persisted lifetime stats.
+pub fn apply_game_over(
+    mut events: EventReader<GameOver>,
+    mut game_state: ResMut<GameState>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for event in events.read() {
+        game_state.announcement = Some(match event.winner {
+            Winner::Player => "You win! The enemy fleet is destroyed.".to_string(),
+            Winner::Opponent => "Game over - your fleet was destroyed.".to_string(),
+        });
+        game_state.placement_mode = PlacementMode::GameOver(event.winner);
+        match event.winner {
+            Winner::Player => settings.games_won += 1,
+            Winner::Opponent => settings.games_lost += 1,
+        }
+        settings.save();
+        println!("Game over: {:?} wins", event.winner);
+    }
+}
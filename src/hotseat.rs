@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use crate::events::{BoardSide, ShotResolved, ShotResult};
+use crate::{CellState, GameState, PlacementMode, ShipType, STANDARD_FLEET_SIZES};
+
+/// Which human is up in a hotseat match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotseatPlayer {
+    One,
+    Two,
+}
+
+impl HotseatPlayer {
+    fn other(self) -> Self {
+        match self {
+            HotseatPlayer::One => HotseatPlayer::Two,
+            HotseatPlayer::Two => HotseatPlayer::One,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HotseatPlayer::One => "Player 1",
+            HotseatPlayer::Two => "Player 2",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotseatPhase {
+    /// Both players are secretly placing their fleets, one after the other.
+    Placing,
+    /// Fleets are locked in; players alternate firing.
+    Battle,
+}
+
+/// Drives local two-player pass-and-play: both fleets are real (unlike the
+/// solo opponent board, whose layout is never known to this client), turns
+/// alternate, and a blocking "pass the device" screen sits between every
+/// turn so neither player sees the board while it isn't their turn.
+#[derive(Resource)]
+pub struct HotseatState {
+    pub enabled: bool,
+    pub phase: HotseatPhase,
+    pub turn: HotseatPlayer,
+    pub awaiting_pass: bool,
+}
+
+impl Default for HotseatState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phase: HotseatPhase::Placing,
+            turn: HotseatPlayer::One,
+            awaiting_pass: false,
+        }
+    }
+}
+
+impl HotseatState {
+    /// Resets a freshly-enabled match to "Player 1 places first".
+    pub fn reset_for_new_match(&mut self) {
+        self.phase = HotseatPhase::Placing;
+        self.turn = HotseatPlayer::One;
+        self.awaiting_pass = false;
+    }
+}
+
+/// Whether the active placer (in `HotseatPhase::Placing`) fills in
+/// `player_board` (Player 1) or `opponent_board` (Player 2). Outside
+/// hotseat mode placement always targets `player_board`.
+pub fn placement_targets_player_board(hotseat: &HotseatState) -> bool {
+    !hotseat.enabled || hotseat.turn == HotseatPlayer::One
+}
+
+#[derive(Component)]
+pub struct PassScreen;
+
+fn spawn_pass_screen(commands: &mut Commands, message: String) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.95)),
+            PassScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+            parent.spawn((
+                Text::new("Press Enter when only they can see the screen"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// Moves placement/battle state forward once a ship has just been placed,
+/// handing off to the other player (with a pass screen) whenever the active
+/// placer's fleet just reached `STANDARD_FLEET_SIZES.len()`.
+pub fn after_ship_placed(hotseat: &mut HotseatState, game_state: &mut GameState, commands: &mut Commands) {
+    if !hotseat.enabled || hotseat.phase != HotseatPhase::Placing {
+        return;
+    }
+
+    match hotseat.turn {
+        HotseatPlayer::One if game_state.ships_placed.len() == STANDARD_FLEET_SIZES.len() => {
+            hotseat.turn = HotseatPlayer::Two;
+            hotseat.awaiting_pass = true;
+            game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Carrier, true);
+            game_state.is_player_board = false;
+            spawn_pass_screen(
+                commands,
+                format!("Pass the device to {}\nPlace your fleet in secret, then press Enter", HotseatPlayer::Two.label()),
+            );
+        }
+        HotseatPlayer::Two if game_state.opponent_ships_placed.len() == STANDARD_FLEET_SIZES.len() => {
+            hotseat.phase = HotseatPhase::Battle;
+            hotseat.turn = HotseatPlayer::One;
+            hotseat.awaiting_pass = true;
+            game_state.placement_mode = PlacementMode::Playing;
+            game_state.is_player_board = battle_target_is_player_board(hotseat);
+            spawn_pass_screen(
+                commands,
+                format!("Pass the device to {}\nBoth fleets are hidden - battle begins!", HotseatPlayer::One.label()),
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Whether the active hotseat player's next shot lands on `player_board`
+/// (true, when Player Two is firing) or `opponent_board` (false, when
+/// Player One is firing).
+pub fn battle_target_is_player_board(hotseat: &HotseatState) -> bool {
+    hotseat.turn == HotseatPlayer::Two
+}
+
+/// Fires at the non-owner board for the active hotseat player, resolving
+/// hit/miss from the real placed fleet instead of manual H/M marking.
+/// Requires the current selection (`game_state.is_player_board`) to already
+/// be on the expected target board, the same guard `handle_mouse_placement`
+/// applies for placement - otherwise a click that moved the selection
+/// elsewhere could fire at a board other than the one highlighted on screen.
+pub fn fire_shot(hotseat: &HotseatState, game_state: &GameState, shot_writer: &mut EventWriter<ShotResolved>) {
+    if game_state.is_player_board != battle_target_is_player_board(hotseat) {
+        return;
+    }
+
+    let x = game_state.selected_x;
+    let y = game_state.selected_y;
+
+    let (board_side, target_board) = match hotseat.turn {
+        HotseatPlayer::One => (BoardSide::Opponent, &game_state.opponent_board),
+        HotseatPlayer::Two => (BoardSide::Player, &game_state.player_board),
+    };
+
+    if target_board[y][x] == CellState::Hit || target_board[y][x] == CellState::Miss {
+        return;
+    }
+
+    let result = if target_board[y][x] == CellState::Ship {
+        ShotResult::Hit
+    } else {
+        ShotResult::Miss
+    };
+
+    shot_writer.write(ShotResolved { board: board_side, x, y, result });
+}
+
+/// Dismisses the pass screen on Enter, unblocking input for the player who
+/// just received the device.
+pub fn handle_pass_screen(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut hotseat: ResMut<HotseatState>,
+    query: Query<Entity, With<PassScreen>>,
+    mut commands: Commands,
+) {
+    if !hotseat.awaiting_pass {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::Enter) {
+        hotseat.awaiting_pass = false;
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Alternates `turn` once a battle-phase shot resolves, putting up a pass
+/// screen before the next player can see the boards - unless that shot just
+/// ended the match, in which case the match-over screen takes over instead.
+pub fn advance_turn(
+    mut shots: EventReader<ShotResolved>,
+    mut game_state: ResMut<GameState>,
+    mut hotseat: ResMut<HotseatState>,
+    mut commands: Commands,
+) {
+    let shot_fired = shots.read().count() > 0;
+
+    if !hotseat.enabled || hotseat.phase != HotseatPhase::Battle || !shot_fired {
+        return;
+    }
+    if matches!(game_state.placement_mode, PlacementMode::GameOver(_)) {
+        return;
+    }
+
+    hotseat.turn = hotseat.turn.other();
+    hotseat.awaiting_pass = true;
+    game_state.is_player_board = battle_target_is_player_board(&hotseat);
+    spawn_pass_screen(&mut commands, format!("Pass the device to {}\nPress Enter when ready to fire", hotseat.turn.label()));
+}
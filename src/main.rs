@@ -4,9 +4,22 @@ use bevy::audio::{PlaybackSettings, Volume};
 use serde::{Deserialize, Serialize};
 use rand::prelude::*;
 use rand::seq::SliceRandom;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+mod ai;
+mod events;
+mod hotseat;
+mod net;
+mod particles;
+
+use ai::AiState;
+use bevy_hanabi::prelude::HanabiPlugin;
+use events::{BoardSide, GameOver, ShipSunk, ShotResolved, ShotResult, Winner};
+use hotseat::{HotseatPhase, HotseatPlayer, HotseatState};
+use net::NetworkClient;
+
 const GRID_SIZE: usize = 10;
 const CELL_SIZE: f32 = 30.0;
 const CELL_SPACING: f32 = 2.0;
@@ -50,10 +63,95 @@ impl ShipType {
     }
 }
 
+/// A pair of cell/ship color palettes, one per board, so the two sides of a
+/// hotseat match are clearly distinguishable at a glance. `Classic` matches
+/// the original single-palette look.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum SkinPair {
+    Classic,
+    OceanCrimson,
+    JadeAmber,
+}
+
+impl SkinPair {
+    fn next(self) -> Self {
+        match self {
+            SkinPair::Classic => SkinPair::OceanCrimson,
+            SkinPair::OceanCrimson => SkinPair::JadeAmber,
+            SkinPair::JadeAmber => SkinPair::Classic,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SkinPair::Classic => "Classic",
+            SkinPair::OceanCrimson => "Ocean / Crimson",
+            SkinPair::JadeAmber => "Jade / Amber",
+        }
+    }
+
+    /// `(empty, ship)` colors for `player_board`.
+    fn player_colors(self) -> (Color, Color) {
+        match self {
+            SkinPair::Classic => (Color::srgb(0.3, 0.3, 0.3), Color::srgb(0.5, 0.5, 0.5)),
+            SkinPair::OceanCrimson => (Color::srgb(0.15, 0.25, 0.35), Color::srgb(0.2, 0.45, 0.7)),
+            SkinPair::JadeAmber => (Color::srgb(0.15, 0.3, 0.25), Color::srgb(0.25, 0.6, 0.45)),
+        }
+    }
+
+    /// `(empty, ship)` colors for `opponent_board`.
+    fn opponent_colors(self) -> (Color, Color) {
+        match self {
+            SkinPair::Classic => (Color::srgb(0.3, 0.3, 0.3), Color::srgb(0.5, 0.5, 0.5)),
+            SkinPair::OceanCrimson => (Color::srgb(0.35, 0.2, 0.15), Color::srgb(0.7, 0.3, 0.2)),
+            SkinPair::JadeAmber => (Color::srgb(0.35, 0.25, 0.15), Color::srgb(0.75, 0.55, 0.2)),
+        }
+    }
+}
+
+/// How many particles a hit/miss/sunk burst spawns, as a fraction of the
+/// full count built into `particles::ImpactEffects`. Lets a player on
+/// low-end hardware scale the GPU particle load down instead of only being
+/// able to turn effects off entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum ParticleDensity {
+    Full,
+    Half,
+    Low,
+}
+
+impl ParticleDensity {
+    fn next(self) -> Self {
+        match self {
+            ParticleDensity::Full => ParticleDensity::Half,
+            ParticleDensity::Half => ParticleDensity::Low,
+            ParticleDensity::Low => ParticleDensity::Full,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ParticleDensity::Full => "Full",
+            ParticleDensity::Half => "Half",
+            ParticleDensity::Low => "Low",
+        }
+    }
+
+    /// Multiplier applied to each effect's built-in particle count.
+    fn scale(self) -> f32 {
+        match self {
+            ParticleDensity::Full => 1.0,
+            ParticleDensity::Half => 0.5,
+            ParticleDensity::Low => 0.2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PlacementMode {
     Playing,
     PlacingShip(ShipType, bool), // ship type, is_horizontal
+    GameOver(Winner),
 }
 
 #[derive(Component)]
@@ -75,10 +173,40 @@ struct CellInfoText;
 #[derive(Component)]
 struct SettingsMenu;
 
+#[derive(Component)]
+struct MatchOverMenu;
+
 #[derive(Resource)]
 struct SoundAssets {
     hit: Handle<AudioSource>,
     miss: Handle<AudioSource>,
+    sunk: Handle<AudioSource>,
+}
+
+/// Which clip a shot resolution should play, so callers pick an outcome
+/// rather than a clip directly.
+enum SoundEvent {
+    Hit,
+    Miss,
+    Sunk,
+}
+
+#[derive(Component)]
+struct MusicTrack;
+
+#[derive(Resource, Default)]
+struct MusicAssets {
+    music_table: Vec<String>,
+    soundtracks: HashMap<String, String>,
+    handles: HashMap<String, Handle<AudioSource>>,
+    current_index: usize,
+}
+
+impl MusicAssets {
+    fn current_track(&self) -> Option<&Handle<AudioSource>> {
+        let name = self.music_table.get(self.current_index)?;
+        self.handles.get(name)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +233,14 @@ struct GameSettings {
     saves: Vec<SaveGame>,
     sound_enabled: bool,
     sound_volume: f32,
+    music_volume: f32,
+    games_won: u32,
+    games_lost: u32,
+    total_hits: u32,
+    total_misses: u32,
+    effects_enabled: bool,
+    skin_pair: SkinPair,
+    particle_density: ParticleDensity,
 }
 
 impl Default for GameSettings {
@@ -115,10 +251,81 @@ impl Default for GameSettings {
             saves: Vec::new(),
             sound_enabled: true,
             sound_volume: 0.7,
+            music_volume: 0.5,
+            games_won: 0,
+            games_lost: 0,
+            total_hits: 0,
+            total_misses: 0,
+            effects_enabled: true,
+            skin_pair: SkinPair::Classic,
+            particle_density: ParticleDensity::Full,
+        }
+    }
+}
+
+impl GameSettings {
+    /// Percentage of resolved shots against the opponent's board that landed a hit.
+    fn accuracy_percent(&self) -> f32 {
+        let total = self.total_hits + self.total_misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.total_hits as f32 / total as f32 * 100.0
         }
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_sound_volume() -> f32 {
+    0.7
+}
+
+fn default_music_volume() -> f32 {
+    0.5
+}
+
+fn default_skin_pair() -> SkinPair {
+    SkinPair::Classic
+}
+
+fn default_particle_density() -> ParticleDensity {
+    ParticleDensity::Full
+}
+
+/// The subset of `GameSettings` that survives between launches. Every field
+/// carries `#[serde(default)]` so a `settings.json` written before a newer
+/// field existed (every chunk after chunk0-2 has added one) still loads -
+/// missing fields fall back instead of failing the whole deserialize and
+/// nuking lifetime stats back to zero.
+#[derive(Serialize, Deserialize)]
+struct PersistedSettings {
+    #[serde(default)]
+    current_save: Option<String>,
+    #[serde(default = "default_true")]
+    sound_enabled: bool,
+    #[serde(default = "default_sound_volume")]
+    sound_volume: f32,
+    #[serde(default = "default_music_volume")]
+    music_volume: f32,
+    #[serde(default)]
+    games_won: u32,
+    #[serde(default)]
+    games_lost: u32,
+    #[serde(default)]
+    total_hits: u32,
+    #[serde(default)]
+    total_misses: u32,
+    #[serde(default = "default_true")]
+    effects_enabled: bool,
+    #[serde(default = "default_skin_pair")]
+    skin_pair: SkinPair,
+    #[serde(default = "default_particle_density")]
+    particle_density: ParticleDensity,
+}
+
 impl GameSettings {
     fn load_all_saves(&mut self) {
         if let Ok(save_dir) = get_save_dir() {
@@ -138,6 +345,51 @@ impl GameSettings {
             }
         }
     }
+
+    fn load(&mut self) {
+        let Ok(path) = settings_path() else {
+            return;
+        };
+        let Ok(json) = fs::read_to_string(path) else {
+            return;
+        };
+        let Ok(persisted) = serde_json::from_str::<PersistedSettings>(&json) else {
+            return;
+        };
+        self.current_save = persisted.current_save;
+        self.sound_enabled = persisted.sound_enabled;
+        self.sound_volume = persisted.sound_volume;
+        self.music_volume = persisted.music_volume;
+        self.games_won = persisted.games_won;
+        self.games_lost = persisted.games_lost;
+        self.total_hits = persisted.total_hits;
+        self.total_misses = persisted.total_misses;
+        self.effects_enabled = persisted.effects_enabled;
+        self.skin_pair = persisted.skin_pair;
+        self.particle_density = persisted.particle_density;
+    }
+
+    fn save(&self) {
+        let Ok(path) = settings_path() else {
+            return;
+        };
+        let persisted = PersistedSettings {
+            current_save: self.current_save.clone(),
+            sound_enabled: self.sound_enabled,
+            sound_volume: self.sound_volume,
+            music_volume: self.music_volume,
+            games_won: self.games_won,
+            games_lost: self.games_lost,
+            total_hits: self.total_hits,
+            total_misses: self.total_misses,
+            effects_enabled: self.effects_enabled,
+            skin_pair: self.skin_pair,
+            particle_density: self.particle_density,
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&persisted) {
+            let _ = fs::write(path, json);
+        }
+    }
 }
 
 #[derive(Resource)]
@@ -150,6 +402,12 @@ struct GameState {
     placement_mode: PlacementMode,
     ships_placed: Vec<ShipType>,
     ship_positions: Vec<PlacedShip>,
+    /// Player Two's real fleet in hotseat mode; unused otherwise, since no
+    /// other opponent (AI, network, solo pass-and-play) has a known layout.
+    opponent_ships_placed: Vec<ShipType>,
+    opponent_ship_positions: Vec<PlacedShip>,
+    /// Last "ship sunk" line to surface in `update_status_text`, if any.
+    announcement: Option<String>,
 }
 
 impl Default for GameState {
@@ -163,6 +421,9 @@ impl Default for GameState {
             placement_mode: PlacementMode::Playing,
             ships_placed: Vec::new(),
             ship_positions: Vec::new(),
+            opponent_ships_placed: Vec::new(),
+            opponent_ship_positions: Vec::new(),
+            announcement: None,
         }
     }
 }
@@ -182,7 +443,70 @@ impl GameState {
         }
         None
     }
-    
+
+    /// Returns every cell occupied by the ship covering `(x, y)`, if any.
+    fn get_ship_cells(&self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+        let ship = self.ship_positions.iter().find(|ship| {
+            if ship.is_horizontal {
+                y == ship.y && x >= ship.x && x < ship.x + ship.ship_type.size()
+            } else {
+                x == ship.x && y >= ship.y && y < ship.y + ship.ship_type.size()
+            }
+        })?;
+
+        Some(
+            (0..ship.ship_type.size())
+                .map(|i| {
+                    if ship.is_horizontal {
+                        (ship.x + i, ship.y)
+                    } else {
+                        (ship.x, ship.y + i)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Mirrors `get_ship_at` against Player Two's fleet; only meaningful
+    /// once hotseat placement has recorded a real `opponent_ship_positions`.
+    fn get_opponent_ship_at(&self, x: usize, y: usize) -> Option<ShipType> {
+        for ship in &self.opponent_ship_positions {
+            if ship.is_horizontal {
+                if y == ship.y && x >= ship.x && x < ship.x + ship.ship_type.size() {
+                    return Some(ship.ship_type);
+                }
+            } else {
+                if x == ship.x && y >= ship.y && y < ship.y + ship.ship_type.size() {
+                    return Some(ship.ship_type);
+                }
+            }
+        }
+        None
+    }
+
+    /// Mirrors `get_ship_cells` against Player Two's fleet.
+    fn get_opponent_ship_cells(&self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+        let ship = self.opponent_ship_positions.iter().find(|ship| {
+            if ship.is_horizontal {
+                y == ship.y && x >= ship.x && x < ship.x + ship.ship_type.size()
+            } else {
+                x == ship.x && y >= ship.y && y < ship.y + ship.ship_type.size()
+            }
+        })?;
+
+        Some(
+            (0..ship.ship_type.size())
+                .map(|i| {
+                    if ship.is_horizontal {
+                        (ship.x + i, ship.y)
+                    } else {
+                        (ship.x, ship.y + i)
+                    }
+                })
+                .collect(),
+        )
+    }
+
     fn save_to_file(&self, name: Option<String>) -> Result<String, Box<dyn std::error::Error>> {
         let save_name = name.unwrap_or_else(generate_random_name);
         let save = SaveGame {
@@ -258,18 +582,224 @@ impl GameState {
         self.opponent_board = [[CellState::Empty; GRID_SIZE]; GRID_SIZE];
         self.ships_placed.clear();
         self.ship_positions.clear();
+        self.opponent_ships_placed.clear();
+        self.opponent_ship_positions.clear();
+        self.announcement = None;
+        self.placement_mode = PlacementMode::Playing;
     }
 }
 
-fn get_save_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+const STANDARD_FLEET_SIZES: [usize; 5] = [5, 4, 3, 3, 2];
+
+/// Resolves which hits on a board with unknown ship layout (e.g. the
+/// opponent's board) can be attributed to an already-sunk ship, by greedily
+/// matching the longest remaining fleet sizes against maximal straight runs
+/// of `Hit` cells. Returns the still-unaccounted-for fleet sizes and the set
+/// of hit cells that are "spoken for" by a resolved sinking.
+fn resolve_sunk_hits(
+    board: &[[CellState; GRID_SIZE]; GRID_SIZE],
+) -> (Vec<usize>, std::collections::HashSet<(usize, usize)>) {
+    let mut runs: Vec<Vec<(usize, usize)>> = Vec::new();
+
+    for y in 0..GRID_SIZE {
+        let mut run = Vec::new();
+        for x in 0..GRID_SIZE {
+            if board[y][x] == CellState::Hit {
+                run.push((x, y));
+            } else if !run.is_empty() {
+                runs.push(std::mem::take(&mut run));
+            }
+        }
+        if !run.is_empty() {
+            runs.push(run);
+        }
+    }
+
+    for x in 0..GRID_SIZE {
+        let mut run = Vec::new();
+        for y in 0..GRID_SIZE {
+            if board[y][x] == CellState::Hit {
+                run.push((x, y));
+            } else if !run.is_empty() {
+                runs.push(std::mem::take(&mut run));
+            }
+        }
+        if !run.is_empty() {
+            runs.push(run);
+        }
+    }
+
+    let mut sizes: Vec<usize> = STANDARD_FLEET_SIZES.to_vec();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut claimed: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+    let mut remaining = Vec::new();
+
+    for size in sizes {
+        if let Some(run) = runs
+            .iter()
+            .find(|run| run.len() == size && run.iter().all(|cell| !claimed.contains(cell)))
+        {
+            claimed.extend(run.iter().copied());
+        } else {
+            remaining.push(size);
+        }
+    }
+
+    (remaining, claimed)
+}
+
+/// Counts, out of the player's placed fleet, how many ships still have at
+/// least one un-`Hit` cell. Returns `(remaining, total)`.
+fn player_ships_remaining(game_state: &GameState) -> (usize, usize) {
+    let total = game_state.ship_positions.len();
+    let remaining = game_state
+        .ship_positions
+        .iter()
+        .filter(|ship| {
+            game_state
+                .get_ship_cells(ship.x, ship.y)
+                .map(|cells| {
+                    !cells
+                        .iter()
+                        .all(|&(x, y)| game_state.player_board[y][x] == CellState::Hit)
+                })
+                .unwrap_or(true)
+        })
+        .count();
+    (remaining, total)
+}
+
+/// Estimates, via `resolve_sunk_hits`, how many of the opponent's fleet are
+/// still afloat. Returns `(remaining, total)`. Used when the opponent's real
+/// layout is unknown; in hotseat, prefer `opponent_ships_remaining_exact`.
+fn opponent_ships_remaining(board: &[[CellState; GRID_SIZE]; GRID_SIZE]) -> (usize, usize) {
+    let (remaining, _claimed) = resolve_sunk_hits(board);
+    (remaining.len(), STANDARD_FLEET_SIZES.len())
+}
+
+/// Exact version of `opponent_ships_remaining` for hotseat, where
+/// `opponent_ship_positions` holds Player Two's real fleet instead of an
+/// unknown layout - mirrors `player_ships_remaining`. Returns `(remaining, total)`.
+fn opponent_ships_remaining_exact(game_state: &GameState) -> (usize, usize) {
+    let total = game_state.opponent_ship_positions.len();
+    let remaining = game_state
+        .opponent_ship_positions
+        .iter()
+        .filter(|ship| {
+            game_state
+                .get_opponent_ship_cells(ship.x, ship.y)
+                .map(|cells| {
+                    !cells
+                        .iter()
+                        .all(|&(x, y)| game_state.opponent_board[y][x] == CellState::Hit)
+                })
+                .unwrap_or(true)
+        })
+        .count();
+    (remaining, total)
+}
+
+/// Builds a per-cell "how likely is a ship here" heatmap for `board` by
+/// sliding every remaining ship size across every legal horizontal and
+/// vertical placement, weighting placements that cover an unresolved hit
+/// much more heavily to bias toward "target mode" around known hits.
+fn compute_density_map(board: &[[CellState; GRID_SIZE]; GRID_SIZE]) -> [[u32; GRID_SIZE]; GRID_SIZE] {
+    let mut density = [[0u32; GRID_SIZE]; GRID_SIZE];
+    let (remaining_sizes, sunk_cells) = resolve_sunk_hits(board);
+
+    let is_legal = |cells: &[(usize, usize)]| {
+        cells
+            .iter()
+            .all(|&(x, y)| board[y][x] != CellState::Miss && !sunk_cells.contains(&(x, y)))
+    };
+
+    for size in remaining_sizes {
+        for y in 0..GRID_SIZE {
+            for x in 0..=GRID_SIZE.saturating_sub(size) {
+                let cells: Vec<(usize, usize)> = (0..size).map(|i| (x + i, y)).collect();
+                if !is_legal(&cells) {
+                    continue;
+                }
+                let hits = cells.iter().filter(|&&(cx, cy)| board[cy][cx] == CellState::Hit).count();
+                let weight = 1 + hits as u32 * 50;
+                for &(cx, cy) in &cells {
+                    density[cy][cx] += weight;
+                }
+            }
+        }
+
+        for x in 0..GRID_SIZE {
+            for y in 0..=GRID_SIZE.saturating_sub(size) {
+                let cells: Vec<(usize, usize)> = (0..size).map(|i| (x, y + i)).collect();
+                if !is_legal(&cells) {
+                    continue;
+                }
+                let hits = cells.iter().filter(|&&(cx, cy)| board[cy][cx] == CellState::Hit).count();
+                let weight = 1 + hits as u32 * 50;
+                for &(cx, cy) in &cells {
+                    density[cy][cx] += weight;
+                }
+            }
+        }
+    }
+
+    density
+}
+
+/// Overlay that recommends the opponent-board cell most likely to hold a ship.
+#[derive(Resource, Default)]
+struct TargetingAdvisor {
+    enabled: bool,
+    density: [[u32; GRID_SIZE]; GRID_SIZE],
+    best_cell: Option<(usize, usize)>,
+}
+
+fn update_targeting_advisor(game_state: Res<GameState>, mut advisor: ResMut<TargetingAdvisor>) {
+    if !advisor.enabled {
+        return;
+    }
+    if !game_state.is_changed() && !advisor.is_changed() {
+        return;
+    }
+
+    advisor.density = compute_density_map(&game_state.opponent_board);
+
+    let mut best: Option<((usize, usize), u32)> = None;
+    for y in 0..GRID_SIZE {
+        for x in 0..GRID_SIZE {
+            if game_state.opponent_board[y][x] != CellState::Empty {
+                continue;
+            }
+            let score = advisor.density[y][x];
+            if best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some(((x, y), score));
+            }
+        }
+    }
+    advisor.best_cell = best.map(|(cell, _)| cell);
+}
+
+fn get_config_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
     let mut path = dirs::config_dir()
         .ok_or("Could not find config directory")?;
     path.push("battleship");
+    Ok(path)
+}
+
+fn get_save_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = get_config_dir()?;
     path.push("saves");
     fs::create_dir_all(&path)?;
     Ok(path)
 }
 
+fn settings_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let path = get_config_dir()?;
+    fs::create_dir_all(&path)?;
+    Ok(path.join("settings.json"))
+}
+
 fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
@@ -313,19 +843,48 @@ fn main() {
             }),
             ..default()
         }))
+        .add_plugins(HanabiPlugin)
         .init_resource::<GameState>()
         .init_resource::<GameSettings>()
-        .add_systems(Startup, (setup, load_sounds))
+        .init_resource::<NetworkClient>()
+        .init_resource::<TargetingAdvisor>()
+        .init_resource::<MousePlacementDrag>()
+        .init_resource::<AiState>()
+        .init_resource::<HotseatState>()
+        .add_event::<ShotResolved>()
+        .add_event::<ShipSunk>()
+        .add_event::<GameOver>()
+        .add_systems(Startup, (setup, load_sounds, load_settings, load_music, particles::setup_impact_effects))
         .add_systems(Update, (
             handle_input,
             handle_mouse_click,
+            handle_mouse_placement,
             update_cell_colors,
             update_selection_indicator,
             update_status_text,
             update_cell_info,
             show_ship_preview,
             handle_settings_menu,
+            handle_match_over_menu,
+            update_music,
+            update_targeting_advisor,
+            ai::ai_take_turn,
+            net::poll_network,
+            hotseat::handle_pass_screen,
+            particles::update_particles,
+            particles::despawn_finished_bursts,
         ))
+        // Chained so a match-ending shot is fully resolved - board mutation,
+        // sinking, and game-over - before `advance_turn` decides whether
+        // there's a next turn to hand off to; otherwise a shot that wins the
+        // match can still flip the turn and raise a pass screen alongside
+        // the match-over screen.
+        .add_systems(Update, (
+            events::apply_shot_resolved,
+            events::apply_ship_sunk,
+            events::apply_game_over,
+            hotseat::advance_turn,
+        ).chain())
         .run();
 }
 
@@ -335,15 +894,71 @@ fn load_sounds(
 ) {
     let hit_sound = asset_server.load("sounds/hit.wav");
     let miss_sound = asset_server.load("sounds/miss.wav");
-    
+    let sunk_sound = asset_server.load("sounds/sunk.wav");
+
     commands.insert_resource(SoundAssets {
         hit: hit_sound,
         miss: miss_sound,
+        sunk: sunk_sound,
     });
-    
+
     println!("Sound effects loaded");
 }
 
+fn load_music(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let soundtracks: HashMap<String, String> = fs::read_to_string("assets/music/manifest.json")
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let mut music_table: Vec<String> = soundtracks.keys().cloned().collect();
+    music_table.sort();
+
+    let handles = soundtracks
+        .iter()
+        .map(|(name, path)| (name.clone(), asset_server.load(path.as_str())))
+        .collect();
+
+    commands.insert_resource(MusicAssets {
+        music_table,
+        soundtracks,
+        handles,
+        current_index: 0,
+    });
+
+    println!("Music manifest loaded");
+}
+
+/// Keeps exactly one looping music track playing while sound is enabled,
+/// restarting it under a new handle whenever the track or sound toggle changes.
+fn update_music(
+    music_assets: Option<Res<MusicAssets>>,
+    settings: Res<GameSettings>,
+    playing: Query<Entity, With<MusicTrack>>,
+    mut commands: Commands,
+) {
+    let Some(music_assets) = music_assets else {
+        return;
+    };
+
+    if !settings.sound_enabled || music_assets.music_table.is_empty() {
+        for entity in playing.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if playing.is_empty() {
+        if let Some(track) = music_assets.current_track() {
+            commands.spawn((
+                AudioPlayer(track.clone()),
+                PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.music_volume)),
+                MusicTrack,
+            ));
+        }
+    }
+}
+
 fn play_sound(
     commands: &mut Commands,
     sound: Handle<AudioSource>,
@@ -357,6 +972,26 @@ fn play_sound(
     }
 }
 
+/// Centralizes hit/miss/sunk clip selection so callers name the outcome
+/// instead of reaching into `SoundAssets` themselves.
+fn play_sound_event(
+    commands: &mut Commands,
+    sounds: &SoundAssets,
+    settings: &GameSettings,
+    event: SoundEvent,
+) {
+    let clip = match event {
+        SoundEvent::Hit => sounds.hit.clone(),
+        SoundEvent::Miss => sounds.miss.clone(),
+        SoundEvent::Sunk => sounds.sunk.clone(),
+    };
+    play_sound(commands, clip, settings);
+}
+
+fn load_settings(mut settings: ResMut<GameSettings>) {
+    settings.load();
+}
+
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
@@ -510,7 +1145,7 @@ fn setup(mut commands: Commands) {
     ));
     
     commands.spawn((
-        Text2d::new("Press 1-5 to place ships | Arrow keys: Move | Space: Rotate | Enter: Place | ESC: Cancel"),
+        Text2d::new("Press 1-5 to place ships | Arrow keys: Move | Space: Rotate | Enter/Click+Drag: Place | ESC: Cancel"),
         TextFont {
             font_size: 16.0,
             ..default()
@@ -521,7 +1156,7 @@ fn setup(mut commands: Commands) {
     ));
     
     commands.spawn((
-        Text2d::new("Controls:\nTab: Switch boards | H: Mark hit | M: Mark miss | C: Clear cell | R: Reset all"),
+        Text2d::new("Controls:\nTab: Switch boards | H: Mark hit | M: Mark miss | C: Clear cell | R: Reset all | T: Next track | G: Targeting advisor"),
         TextFont {
             font_size: 16.0,
             ..default()
@@ -531,13 +1166,29 @@ fn setup(mut commands: Commands) {
     ));
 }
 
+const ANTARES_DEFAULT_ADDR: &str = "127.0.0.1:2571";
+
 fn handle_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut game_state: ResMut<GameState>,
     mut settings: ResMut<GameSettings>,
     mut commands: Commands,
-    sounds: Option<Res<SoundAssets>>,
+    mut network: ResMut<NetworkClient>,
+    mut music_assets: Option<ResMut<MusicAssets>>,
+    music_playing: Query<Entity, With<MusicTrack>>,
+    mut advisor: ResMut<TargetingAdvisor>,
+    mut ai_state: ResMut<AiState>,
+    mut hotseat: ResMut<HotseatState>,
+    mut shot_writer: EventWriter<ShotResolved>,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    mut drag: ResMut<MousePlacementDrag>,
 ) {
+    // The pass-device screen owns input until `hotseat::handle_pass_screen`
+    // dismisses it, so the next player doesn't see a stray keypress land.
+    if hotseat.awaiting_pass {
+        return;
+    }
+
     // Toggle settings menu with Escape
     if keyboard.just_pressed(KeyCode::Escape) {
         if matches!(game_state.placement_mode, PlacementMode::PlacingShip(_, _)) {
@@ -546,9 +1197,9 @@ fn handle_input(
             settings.show_settings = !settings.show_settings;
             if settings.show_settings {
                 settings.load_all_saves();
-                spawn_settings_menu(&mut commands, &settings);
+                spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
             } else {
-                despawn_settings_menu(&mut commands);
+                despawn_settings_menu(&mut commands, &settings_menu_query);
             }
         }
         return;
@@ -560,13 +1211,80 @@ fn handle_input(
         if keyboard.just_pressed(KeyCode::KeyS) {
             settings.sound_enabled = !settings.sound_enabled;
             println!("Sound {}", if settings.sound_enabled { "enabled" } else { "disabled" });
+            settings.save();
             // Refresh the settings menu to show updated state
-            despawn_settings_menu(&mut commands);
+            despawn_settings_menu(&mut commands, &settings_menu_query);
             settings.load_all_saves();
-            spawn_settings_menu(&mut commands, &settings);
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
             return;
         }
-        
+
+        if keyboard.just_pressed(KeyCode::BracketLeft) || keyboard.just_pressed(KeyCode::BracketRight) {
+            let delta = if keyboard.just_pressed(KeyCode::BracketRight) { 0.05 } else { -0.05 };
+            settings.sound_volume = (settings.sound_volume + delta).clamp(0.0, 1.0);
+            settings.save();
+            println!("Sound volume: {}%", (settings.sound_volume * 100.0) as i32);
+            despawn_settings_menu(&mut commands, &settings_menu_query);
+            settings.load_all_saves();
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
+            return;
+        }
+
+        if keyboard.just_pressed(KeyCode::Minus) || keyboard.just_pressed(KeyCode::Equal) {
+            let delta = if keyboard.just_pressed(KeyCode::Equal) { 0.05 } else { -0.05 };
+            settings.music_volume = (settings.music_volume + delta).clamp(0.0, 1.0);
+            settings.save();
+            println!("Music volume: {}%", (settings.music_volume * 100.0) as i32);
+            // `update_music` only spawns a track when none is playing, so the
+            // new volume wouldn't be heard until the track was toggled off/on
+            // or cycled - despawn it here too, same as the `T` cycle handler,
+            // so `update_music` respawns it under the new volume next frame.
+            for entity in music_playing.iter() {
+                commands.entity(entity).despawn();
+            }
+            despawn_settings_menu(&mut commands, &settings_menu_query);
+            settings.load_all_saves();
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
+            return;
+        }
+
+        // Toggle hit/miss/sunk particle effects with E key
+        if keyboard.just_pressed(KeyCode::KeyE) {
+            settings.effects_enabled = !settings.effects_enabled;
+            println!("Particle effects {}", if settings.effects_enabled { "enabled" } else { "disabled" });
+            settings.save();
+            // Refresh the settings menu to show updated state
+            despawn_settings_menu(&mut commands, &settings_menu_query);
+            settings.load_all_saves();
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
+            return;
+        }
+
+        // Cycle board skin with V key
+        if keyboard.just_pressed(KeyCode::KeyV) {
+            settings.skin_pair = settings.skin_pair.next();
+            println!("Board skin: {}", settings.skin_pair.name());
+            settings.save();
+            // Refresh the settings menu to show updated state
+            despawn_settings_menu(&mut commands, &settings_menu_query);
+            settings.load_all_saves();
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
+            return;
+        }
+
+        // Cycle particle density with P key, to scale GPU particle bursts
+        // down on low-end hardware without turning effects off entirely.
+        if keyboard.just_pressed(KeyCode::KeyP) {
+            settings.particle_density = settings.particle_density.next();
+            println!("Particle density: {}", settings.particle_density.name());
+            settings.save();
+            // Refresh the settings menu to show updated state
+            despawn_settings_menu(&mut commands, &settings_menu_query);
+            settings.load_all_saves();
+            spawn_settings_menu(&mut commands, &settings, hotseat.enabled);
+            return;
+        }
+
         for i in 1..=5 {
             let key = match i {
                 1 => KeyCode::Digit1,
@@ -584,8 +1302,10 @@ fn handle_input(
                         eprintln!("Failed to load save {}: {}", save_name, e);
                     } else {
                         settings.current_save = Some(save_name.clone());
+                        settings.save();
                         settings.show_settings = false;
-                        despawn_settings_menu(&mut commands);
+                        despawn_settings_menu(&mut commands, &settings_menu_query);
+                        ai_state.reset();
                         println!("Loaded: {}", save_name);
                     }
                 }
@@ -601,6 +1321,7 @@ fn handle_input(
                 Ok(name) => {
                     println!("Game saved as: {}", name);
                     settings.current_save = Some(name.clone());
+                    settings.save();
                     settings.load_all_saves();
                 }
                 Err(e) => eprintln!("Failed to save game: {}", e),
@@ -611,6 +1332,7 @@ fn handle_input(
                 if let Err(e) = game_state.load_from_file(current) {
                     eprintln!("Failed to load game: {}", e);
                 } else {
+                    ai_state.reset();
                     println!("Game loaded: {}", current);
                 }
             } else if !settings.saves.is_empty() {
@@ -619,6 +1341,8 @@ fn handle_input(
                     eprintln!("Failed to load game: {}", e);
                 } else {
                     settings.current_save = Some(first_save_name.clone());
+                    settings.save();
+                    ai_state.reset();
                     println!("Game loaded: {}", first_save_name);
                 }
             } else {
@@ -627,15 +1351,68 @@ fn handle_input(
         }
         if keyboard.just_pressed(KeyCode::KeyN) {
             game_state.clear_board();
+            ai_state.reset();
+            if hotseat.enabled {
+                hotseat.reset_for_new_match();
+                game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Carrier, true);
+                game_state.is_player_board = true;
+            }
             settings.current_save = None;
+            settings.save();
             println!("New game started!");
         }
         if keyboard.just_pressed(KeyCode::KeyP) {
             game_state.place_random_ships();
+            ai_state.reset();
             println!("Ships placed randomly!");
         }
+        if keyboard.just_pressed(KeyCode::KeyO) {
+            match network.connect(ANTARES_DEFAULT_ADDR) {
+                Ok(()) => println!("Connecting to {}...", ANTARES_DEFAULT_ADDR),
+                Err(e) => eprintln!("Failed to connect to server: {}", e),
+            }
+        }
+        if keyboard.just_pressed(KeyCode::KeyA) {
+            ai_state.enabled = !ai_state.enabled;
+            println!("AI opponent {}", if ai_state.enabled { "enabled" } else { "disabled" });
+        }
+        if keyboard.just_pressed(KeyCode::KeyH) {
+            hotseat.enabled = !hotseat.enabled;
+            if hotseat.enabled {
+                game_state.clear_board();
+                ai_state.reset();
+                hotseat.reset_for_new_match();
+                game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Carrier, true);
+                game_state.is_player_board = true;
+                println!("Hotseat mode enabled - {} places first", HotseatPlayer::One.label());
+            } else {
+                println!("Hotseat mode disabled");
+            }
+            return;
+        }
     }
-    
+
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        advisor.enabled = !advisor.enabled;
+        println!("Targeting advisor {}", if advisor.enabled { "enabled" } else { "disabled" });
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        if let Some(ref mut music_assets) = music_assets {
+            if !music_assets.music_table.is_empty() {
+                music_assets.current_index =
+                    (music_assets.current_index + 1) % music_assets.music_table.len();
+                for entity in music_playing.iter() {
+                    commands.entity(entity).despawn();
+                }
+                println!(
+                    "Now playing: {}",
+                    music_assets.music_table[music_assets.current_index]
+                );
+            }
+        }
+    }
+
     if keyboard.just_pressed(KeyCode::ArrowUp) && game_state.selected_y < GRID_SIZE - 1 {
         game_state.selected_y += 1;
     }
@@ -653,142 +1430,325 @@ fn handle_input(
         PlacementMode::PlacingShip(ship_type, is_horizontal) => {
             if keyboard.just_pressed(KeyCode::Space) {
                 game_state.placement_mode = PlacementMode::PlacingShip(ship_type, !is_horizontal);
+                // Mark this an explicit rotation so a drag in progress stops
+                // re-deriving orientation from drag direction and clobbering it.
+                if drag.anchor.is_some() {
+                    drag.manual_override = true;
+                }
             }
             
             if keyboard.just_pressed(KeyCode::Enter) {
                 let x = game_state.selected_x;
                 let y = game_state.selected_y;
-                if can_place_ship(&game_state.player_board, x, y, ship_type.size(), is_horizontal) {
-                    place_ship(&mut game_state.player_board, x, y, ship_type.size(), is_horizontal);
-                    game_state.ships_placed.push(ship_type);
-                    game_state.ship_positions.push(PlacedShip {
-                        ship_type,
-                        x,
-                        y,
-                        is_horizontal,
-                    });
+                let targets_player_board = hotseat::placement_targets_player_board(&hotseat);
+                let board = if targets_player_board {
+                    &mut game_state.player_board
+                } else {
+                    &mut game_state.opponent_board
+                };
+                if can_place_ship(board, x, y, ship_type.size(), is_horizontal) {
+                    place_ship(board, x, y, ship_type.size(), is_horizontal);
+                    let placed_ship = PlacedShip { ship_type, x, y, is_horizontal };
+                    if targets_player_board {
+                        game_state.ships_placed.push(ship_type);
+                        game_state.ship_positions.push(placed_ship);
+                    } else {
+                        game_state.opponent_ships_placed.push(ship_type);
+                        game_state.opponent_ship_positions.push(placed_ship);
+                    }
                     game_state.placement_mode = PlacementMode::Playing;
+                    hotseat::after_ship_placed(&mut hotseat, &mut game_state, &mut commands);
                 }
             }
         }
         PlacementMode::Playing => {
+            if hotseat.enabled && hotseat.phase == HotseatPhase::Battle {
+                if keyboard.just_pressed(KeyCode::Enter) {
+                    hotseat::fire_shot(&hotseat, &game_state, &mut shot_writer);
+                }
+                return;
+            }
+
             if keyboard.just_pressed(KeyCode::Tab) {
                 game_state.is_player_board = !game_state.is_player_board;
             }
 
-            if keyboard.just_pressed(KeyCode::Digit1) && !game_state.ships_placed.contains(&ShipType::Carrier) {
+            // In hotseat's placement phase, ships already placed by the
+            // *active placer* gate these digit keys; otherwise it's always
+            // the player's own list.
+            let targets_player_board = hotseat::placement_targets_player_board(&hotseat);
+            let active_ships_placed = if targets_player_board {
+                &game_state.ships_placed
+            } else {
+                &game_state.opponent_ships_placed
+            };
+            let carrier_placed = active_ships_placed.contains(&ShipType::Carrier);
+            let battleship_placed = active_ships_placed.contains(&ShipType::Battleship);
+            let cruiser_placed = active_ships_placed.contains(&ShipType::Cruiser);
+            let submarine_placed = active_ships_placed.contains(&ShipType::Submarine);
+            let destroyer_placed = active_ships_placed.contains(&ShipType::Destroyer);
+
+            if keyboard.just_pressed(KeyCode::Digit1) && !carrier_placed {
                 game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Carrier, true);
-                game_state.is_player_board = true;
+                game_state.is_player_board = targets_player_board;
             }
-            if keyboard.just_pressed(KeyCode::Digit2) && !game_state.ships_placed.contains(&ShipType::Battleship) {
+            if keyboard.just_pressed(KeyCode::Digit2) && !battleship_placed {
                 game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Battleship, true);
-                game_state.is_player_board = true;
+                game_state.is_player_board = targets_player_board;
             }
-            if keyboard.just_pressed(KeyCode::Digit3) && !game_state.ships_placed.contains(&ShipType::Cruiser) {
+            if keyboard.just_pressed(KeyCode::Digit3) && !cruiser_placed {
                 game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Cruiser, true);
-                game_state.is_player_board = true;
+                game_state.is_player_board = targets_player_board;
             }
-            if keyboard.just_pressed(KeyCode::Digit4) && !game_state.ships_placed.contains(&ShipType::Submarine) {
+            if keyboard.just_pressed(KeyCode::Digit4) && !submarine_placed {
                 game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Submarine, true);
-                game_state.is_player_board = true;
+                game_state.is_player_board = targets_player_board;
             }
-            if keyboard.just_pressed(KeyCode::Digit5) && !game_state.ships_placed.contains(&ShipType::Destroyer) {
+            if keyboard.just_pressed(KeyCode::Digit5) && !destroyer_placed {
                 game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Destroyer, true);
-                game_state.is_player_board = true;
+                game_state.is_player_board = targets_player_board;
             }
 
             let x = game_state.selected_x;
             let y = game_state.selected_y;
 
-            if game_state.is_player_board {
-                if keyboard.just_pressed(KeyCode::KeyH) {
-                    if game_state.player_board[y][x] == CellState::Ship {
-                        game_state.player_board[y][x] = CellState::Hit;
-                        if let Some(ref sounds) = sounds {
-                            play_sound(&mut commands, sounds.hit.clone(), &settings);
+            // The manual H/M/C marking and network firing below assume a
+            // single local player; hotseat resolves shots automatically via
+            // the branch above instead, for both players' boards.
+            if !hotseat.enabled {
+                if game_state.is_player_board {
+                    if keyboard.just_pressed(KeyCode::KeyH) {
+                        if game_state.player_board[y][x] == CellState::Ship {
+                            shot_writer.write(ShotResolved {
+                                board: BoardSide::Player,
+                                x,
+                                y,
+                                result: ShotResult::Hit,
+                            });
                         }
                     }
-                }
-                if keyboard.just_pressed(KeyCode::KeyM) {
-                    if game_state.player_board[y][x] != CellState::Ship {
-                        game_state.player_board[y][x] = CellState::Miss;
-                        if let Some(ref sounds) = sounds {
-                            play_sound(&mut commands, sounds.miss.clone(), &settings);
+                    if keyboard.just_pressed(KeyCode::KeyM) {
+                        if game_state.player_board[y][x] != CellState::Ship {
+                            shot_writer.write(ShotResolved {
+                                board: BoardSide::Player,
+                                x,
+                                y,
+                                result: ShotResult::Miss,
+                            });
                         }
                     }
-                }
-                if keyboard.just_pressed(KeyCode::KeyC) {
-                    game_state.player_board[y][x] = CellState::Empty;
-                }
-            } else {
-                if keyboard.just_pressed(KeyCode::KeyH) {
-                    game_state.opponent_board[y][x] = CellState::Hit;
-                    if let Some(ref sounds) = sounds {
-                        play_sound(&mut commands, sounds.hit.clone(), &settings);
+                    if keyboard.just_pressed(KeyCode::KeyC) {
+                        game_state.player_board[y][x] = CellState::Empty;
                     }
-                }
-                if keyboard.just_pressed(KeyCode::KeyM) {
-                    game_state.opponent_board[y][x] = CellState::Miss;
-                    if let Some(ref sounds) = sounds {
-                        play_sound(&mut commands, sounds.miss.clone(), &settings);
+                } else if network.connected && network.my_turn {
+                    if keyboard.just_pressed(KeyCode::Enter) {
+                        network.send_shot(x, y);
+                        network.my_turn = false;
+                        println!("Fired at {}, {}", x, y);
+                    }
+                } else {
+                    // A cell already marked Hit/Miss has already been counted
+                    // toward the lifetime accuracy tally; only an `Empty` cell
+                    // can still be fired at, the way the player-board `H`
+                    // branch above is limited by its `== CellState::Ship` check.
+                    if keyboard.just_pressed(KeyCode::KeyH) {
+                        if game_state.opponent_board[y][x] == CellState::Empty {
+                            shot_writer.write(ShotResolved {
+                                board: BoardSide::Opponent,
+                                x,
+                                y,
+                                result: ShotResult::Hit,
+                            });
+                        }
+                    }
+                    if keyboard.just_pressed(KeyCode::KeyM) {
+                        if game_state.opponent_board[y][x] == CellState::Empty {
+                            shot_writer.write(ShotResolved {
+                                board: BoardSide::Opponent,
+                                x,
+                                y,
+                                result: ShotResult::Miss,
+                            });
+                        }
+                    }
+                    if keyboard.just_pressed(KeyCode::KeyC) {
+                        game_state.opponent_board[y][x] = CellState::Empty;
                     }
                 }
-                if keyboard.just_pressed(KeyCode::KeyC) {
-                    game_state.opponent_board[y][x] = CellState::Empty;
+
+                if keyboard.just_pressed(KeyCode::KeyR) {
+                    game_state.player_board = [[CellState::Empty; GRID_SIZE]; GRID_SIZE];
+                    game_state.opponent_board = [[CellState::Empty; GRID_SIZE]; GRID_SIZE];
+                    game_state.ships_placed.clear();
+                    game_state.ship_positions.clear();
+                    game_state.announcement = None;
+                    ai_state.reset();
                 }
             }
-
+        }
+        PlacementMode::GameOver(_) => {
             if keyboard.just_pressed(KeyCode::KeyR) {
-                game_state.player_board = [[CellState::Empty; GRID_SIZE]; GRID_SIZE];
-                game_state.opponent_board = [[CellState::Empty; GRID_SIZE]; GRID_SIZE];
-                game_state.ships_placed.clear();
-                game_state.ship_positions.clear();
+                game_state.clear_board();
+                ai_state.reset();
+                if hotseat.enabled {
+                    hotseat.reset_for_new_match();
+                    game_state.placement_mode = PlacementMode::PlacingShip(ShipType::Carrier, true);
+                    game_state.is_player_board = true;
+                }
+                println!("Starting a new match!");
             }
         }
     }
 }
 
+/// Resolves the board cell (if any) currently under the mouse cursor.
+fn hovered_cell(
+    q_windows: &Query<&Window, With<PrimaryWindow>>,
+    q_camera: &Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    q_cells: &Query<(&Cell, &Transform)>,
+) -> Option<(usize, usize, bool)> {
+    let window = q_windows.single().ok()?;
+    let cursor_position = window.cursor_position()?;
+    let (camera, camera_transform) = q_camera.single().ok()?;
+    let world_position = camera.viewport_to_world_2d(camera_transform, cursor_position).ok()?;
+
+    for (cell, transform) in q_cells.iter() {
+        let half_size = CELL_SIZE / 2.0;
+        let min_x = transform.translation.x - half_size;
+        let max_x = transform.translation.x + half_size;
+        let min_y = transform.translation.y - half_size;
+        let max_y = transform.translation.y + half_size;
+
+        if world_position.x >= min_x && world_position.x <= max_x &&
+           world_position.y >= min_y && world_position.y <= max_y {
+            return Some((cell.x, cell.y, cell.is_player_board));
+        }
+    }
+    None
+}
+
 fn handle_mouse_click(
     buttons: Res<ButtonInput<MouseButton>>,
     q_windows: Query<&Window, With<PrimaryWindow>>,
     q_camera: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
     q_cells: Query<(&Cell, &Transform)>,
     mut game_state: ResMut<GameState>,
+    hotseat: Res<HotseatState>,
 ) {
     if !buttons.just_pressed(MouseButton::Left) {
         return;
     }
 
-    let Ok(window) = q_windows.single() else {
+    // Ship placement owns left-click while it's in progress; see handle_mouse_placement.
+    if matches!(game_state.placement_mode, PlacementMode::PlacingShip(_, _)) {
         return;
-    };
+    }
+
+    if let Some((x, y, is_player_board)) = hovered_cell(&q_windows, &q_camera, &q_cells) {
+        // During a hotseat battle only the active player's firing board can
+        // be selected, so a stray click can't move the selection to a board
+        // `hotseat::fire_shot` then refuses to fire at.
+        if hotseat.enabled
+            && hotseat.phase == HotseatPhase::Battle
+            && is_player_board != hotseat::battle_target_is_player_board(&hotseat)
+        {
+            return;
+        }
+        game_state.selected_x = x;
+        game_state.selected_y = y;
+        game_state.is_player_board = is_player_board;
+    }
+}
+
+/// Tracks the anchor cell of an in-progress mouse-driven ship placement.
+#[derive(Resource, Default)]
+struct MousePlacementDrag {
+    anchor: Option<(usize, usize)>,
+    /// Set once the player hits Space mid-drag to flip orientation by hand;
+    /// while set, `handle_mouse_placement` stops re-deriving orientation from
+    /// the drag direction so the manual flip sticks instead of being
+    /// clobbered the next time the cursor moves over a non-anchor cell.
+    manual_override: bool,
+}
 
-    let Some(cursor_position) = window.cursor_position() else {
+fn handle_mouse_placement(
+    buttons: Res<ButtonInput<MouseButton>>,
+    q_windows: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    q_cells: Query<(&Cell, &Transform)>,
+    mut game_state: ResMut<GameState>,
+    mut drag: ResMut<MousePlacementDrag>,
+    mut hotseat: ResMut<HotseatState>,
+    mut commands: Commands,
+) {
+    let PlacementMode::PlacingShip(ship_type, mut is_horizontal) = game_state.placement_mode else {
+        drag.anchor = None;
+        drag.manual_override = false;
         return;
     };
 
-    let Ok((camera, camera_transform)) = q_camera.single() else {
+    if hotseat.awaiting_pass {
         return;
-    };
+    }
+
+    let targets_player_board = hotseat::placement_targets_player_board(&hotseat);
+
+    if buttons.just_pressed(MouseButton::Left) {
+        if let Some((x, y, is_player_board)) = hovered_cell(&q_windows, &q_camera, &q_cells) {
+            if is_player_board == targets_player_board {
+                drag.anchor = Some((x, y));
+                drag.manual_override = false;
+                game_state.selected_x = x;
+                game_state.selected_y = y;
+            }
+        }
+        return;
+    }
 
-    let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, cursor_position) else {
+    let Some((anchor_x, anchor_y)) = drag.anchor else {
         return;
     };
 
-    for (cell, transform) in q_cells.iter() {
-        let half_size = CELL_SIZE / 2.0;
-        let min_x = transform.translation.x - half_size;
-        let max_x = transform.translation.x + half_size;
-        let min_y = transform.translation.y - half_size;
-        let max_y = transform.translation.y + half_size;
+    if buttons.pressed(MouseButton::Left) && !drag.manual_override {
+        if let Some((x, y, is_player_board)) = hovered_cell(&q_windows, &q_camera, &q_cells) {
+            if is_player_board == targets_player_board {
+                let dx = x as i32 - anchor_x as i32;
+                let dy = y as i32 - anchor_y as i32;
+                if dx != 0 || dy != 0 {
+                    is_horizontal = dx.abs() >= dy.abs();
+                    game_state.placement_mode = PlacementMode::PlacingShip(ship_type, is_horizontal);
+                }
+            }
+        }
+    }
 
-        if world_position.x >= min_x && world_position.x <= max_x &&
-           world_position.y >= min_y && world_position.y <= max_y {
-            game_state.selected_x = cell.x;
-            game_state.selected_y = cell.y;
-            game_state.is_player_board = cell.is_player_board;
-            break;
+    if buttons.just_released(MouseButton::Left) {
+        let size = ship_type.size();
+        let targets_player_board = hotseat::placement_targets_player_board(&hotseat);
+        let board = if targets_player_board {
+            &mut game_state.player_board
+        } else {
+            &mut game_state.opponent_board
+        };
+        if can_place_ship(board, anchor_x, anchor_y, size, is_horizontal) {
+            place_ship(board, anchor_x, anchor_y, size, is_horizontal);
+            let placed_ship = PlacedShip {
+                ship_type,
+                x: anchor_x,
+                y: anchor_y,
+                is_horizontal,
+            };
+            if targets_player_board {
+                game_state.ships_placed.push(ship_type);
+                game_state.ship_positions.push(placed_ship);
+            } else {
+                game_state.opponent_ships_placed.push(ship_type);
+                game_state.opponent_ship_positions.push(placed_ship);
+            }
+            game_state.placement_mode = PlacementMode::Playing;
+            hotseat::after_ship_placed(&mut hotseat, &mut game_state, &mut commands);
         }
+        drag.anchor = None;
     }
 }
 
@@ -827,10 +1787,43 @@ fn place_ship(board: &mut [[CellState; GRID_SIZE]; GRID_SIZE], x: usize, y: usiz
     }
 }
 
+/// World-space center of a board cell, using the same offsets as `setup`.
+fn cell_world_pos(is_player_board: bool, x: usize, y: usize) -> Vec2 {
+    let board_width = GRID_SIZE as f32 * (CELL_SIZE + CELL_SPACING);
+    let shift_left = -board_width / 2.0;
+    let board_offset = if is_player_board {
+        -board_width / 2.0 - 40.0 + shift_left
+    } else {
+        board_width / 2.0 + 40.0 + shift_left
+    };
+
+    Vec2::new(
+        board_offset + x as f32 * (CELL_SIZE + CELL_SPACING) + CELL_SIZE / 2.0,
+        y as f32 * (CELL_SIZE + CELL_SPACING) - 150.0 + CELL_SIZE / 2.0,
+    )
+}
+
 fn update_cell_colors(
     game_state: Res<GameState>,
+    advisor: Res<TargetingAdvisor>,
+    settings: Res<GameSettings>,
+    hotseat: Res<HotseatState>,
     mut query: Query<(&Cell, &mut Sprite)>,
 ) {
+    let max_density = advisor
+        .density
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .filter(|&max| max > 0);
+
+    // In hotseat, only the active player's own fleet is shown as ships; the
+    // other board's ships stay hidden until hit, since both fleets are real
+    // and neither player should see where the other placed.
+    let fog_of_war = hotseat.enabled;
+    let active_player_owns_player_board = hotseat.turn == HotseatPlayer::One;
+
     for (cell, mut sprite) in query.iter_mut() {
         let state = if cell.is_player_board {
             game_state.player_board[cell.y][cell.x]
@@ -838,12 +1831,34 @@ fn update_cell_colors(
             game_state.opponent_board[cell.y][cell.x]
         };
 
+        let (empty_color, ship_color) = if cell.is_player_board {
+            settings.skin_pair.player_colors()
+        } else {
+            settings.skin_pair.opponent_colors()
+        };
+
+        let is_own_board = cell.is_player_board == active_player_owns_player_board;
+        let hide_ship = fog_of_war && !is_own_board;
+
         sprite.color = match state {
-            CellState::Empty => Color::srgb(0.3, 0.3, 0.3),
-            CellState::Ship => Color::srgb(0.5, 0.5, 0.5),
+            CellState::Empty => empty_color,
+            CellState::Ship if hide_ship => empty_color,
+            CellState::Ship => ship_color,
             CellState::Hit => Color::srgb(1.0, 0.0, 0.0),
             CellState::Miss => Color::srgb(0.0, 0.0, 1.0),
         };
+
+        if advisor.enabled && !cell.is_player_board && state == CellState::Empty {
+            if let Some(max_density) = max_density {
+                let score = advisor.density[cell.y][cell.x];
+                if Some((cell.x, cell.y)) == advisor.best_cell {
+                    sprite.color = Color::srgb(1.0, 1.0, 0.0);
+                } else if score > 0 {
+                    let intensity = score as f32 / max_density as f32;
+                    sprite.color = Color::srgb(0.3 + intensity * 0.6, 0.3, 0.3);
+                }
+            }
+        }
     }
 }
 
@@ -870,17 +1885,41 @@ fn update_selection_indicator(
 
 fn update_status_text(
     game_state: Res<GameState>,
+    hotseat: Res<HotseatState>,
     mut query: Query<&mut Text2d, With<StatusText>>,
 ) {
     if let Ok(mut text) = query.single_mut() {
         text.0 = match game_state.placement_mode {
             PlacementMode::PlacingShip(ship_type, is_horizontal) => {
+                let prefix = if hotseat.enabled {
+                    format!("{}: ", hotseat.turn.label())
+                } else {
+                    String::new()
+                };
                 format!(
-                    "Placing {} - {} | Space: Rotate | Enter: Place | ESC: Cancel",
+                    "{}Placing {} - {} | Space: Rotate | Enter: Place | ESC: Cancel",
+                    prefix,
                     ship_type.name(),
                     if is_horizontal { "Horizontal" } else { "Vertical" }
                 )
             }
+            PlacementMode::Playing if game_state.announcement.is_some() => {
+                game_state.announcement.clone().unwrap()
+            }
+            PlacementMode::Playing if hotseat.enabled => {
+                let active_ships_placed = if hotseat.turn == HotseatPlayer::One {
+                    &game_state.ships_placed
+                } else {
+                    &game_state.opponent_ships_placed
+                };
+                if hotseat.phase == HotseatPhase::Battle {
+                    format!("{}'s turn - Enter: Fire", hotseat.turn.label())
+                } else if active_ships_placed.len() == STANDARD_FLEET_SIZES.len() {
+                    format!("{}: All ships placed! Game ready.", hotseat.turn.label())
+                } else {
+                    format!("{}: Place your fleet", hotseat.turn.label())
+                }
+            }
             PlacementMode::Playing => {
                 let mut ships_to_place = Vec::new();
                 if !game_state.ships_placed.contains(&ShipType::Carrier) {
@@ -898,58 +1937,103 @@ fn update_status_text(
                 if !game_state.ships_placed.contains(&ShipType::Destroyer) {
                     ships_to_place.push("5:Destroyer(2)");
                 }
-                
+
                 if ships_to_place.is_empty() {
                     "All ships placed! Game ready.".to_string()
                 } else {
                     format!("Ships to place: {}", ships_to_place.join(" | "))
                 }
             }
+            PlacementMode::GameOver(winner) => {
+                let headline = if hotseat.enabled {
+                    match winner {
+                        Winner::Player => format!("{} wins! The enemy fleet is destroyed.", HotseatPlayer::One.label()),
+                        Winner::Opponent => format!("{} wins! The enemy fleet is destroyed.", HotseatPlayer::Two.label()),
+                    }
+                } else {
+                    match winner {
+                        Winner::Player => "Victory! The enemy fleet is destroyed.".to_string(),
+                        Winner::Opponent => "Defeat - your fleet was destroyed.".to_string(),
+                    }
+                };
+                format!("{} | Press R to play again", headline)
+            }
         };
     }
 }
 
 fn update_cell_info(
     game_state: Res<GameState>,
+    hotseat: Res<HotseatState>,
     mut query: Query<&mut Text2d, With<CellInfoText>>,
 ) {
     if let Ok(mut text) = query.single_mut() {
         let col = (b'A' + game_state.selected_x as u8) as char;
         let row = GRID_SIZE - game_state.selected_y;
         let coord = format!("{}{}", col, row);
-        
-        let board_name = if game_state.is_player_board {
-            "Your Board"
+
+        let board_name = if hotseat.enabled {
+            if game_state.is_player_board {
+                format!("{}'s Board", HotseatPlayer::One.label())
+            } else {
+                format!("{}'s Board", HotseatPlayer::Two.label())
+            }
+        } else if game_state.is_player_board {
+            "Your Board".to_string()
         } else {
-            "Opponent's Board"
+            "Opponent's Board".to_string()
         };
-        
+
         let state = if game_state.is_player_board {
             game_state.player_board[game_state.selected_y][game_state.selected_x]
         } else {
             game_state.opponent_board[game_state.selected_y][game_state.selected_x]
         };
-        
-        let state_str = match state {
+
+        // Outside hotseat, only the player's own board has a known layout to
+        // name; in hotseat both boards are real fleets, so whichever one is
+        // the active placer's own board can show its ship name too.
+        let can_reveal_ship = if hotseat.enabled {
+            game_state.is_player_board == (hotseat.turn == HotseatPlayer::One)
+        } else {
+            game_state.is_player_board
+        };
+
+        // Same fog-of-war `can_reveal_ship` gates the state text too: an
+        // un-hit `Ship` on the board that isn't the active player's own
+        // would otherwise leak its layout through this text even though
+        // `update_cell_colors` hides it on the grid.
+        let display_state = if hotseat.enabled && !can_reveal_ship && state == CellState::Ship {
+            CellState::Empty
+        } else {
+            state
+        };
+
+        let state_str = match display_state {
             CellState::Empty => "Empty",
             CellState::Ship => "Ship",
             CellState::Hit => "Hit",
             CellState::Miss => "Miss",
         };
-        
+
         let mut info = format!("Cell: {} | Board: {} | State: {}", coord, board_name, state_str);
-        
-        if game_state.is_player_board && state == CellState::Ship {
-            if let Some(ship_type) = game_state.get_ship_at(game_state.selected_x, game_state.selected_y) {
+
+        if can_reveal_ship && state == CellState::Ship {
+            let ship_type = if game_state.is_player_board {
+                game_state.get_ship_at(game_state.selected_x, game_state.selected_y)
+            } else {
+                game_state.get_opponent_ship_at(game_state.selected_x, game_state.selected_y)
+            };
+            if let Some(ship_type) = ship_type {
                 info.push_str(&format!(" | Ship: {}", ship_type.name()));
             }
         }
-        
+
         text.0 = info;
     }
 }
 
-fn spawn_settings_menu(commands: &mut Commands, settings: &GameSettings) {
+fn spawn_settings_menu(commands: &mut Commands, settings: &GameSettings, hotseat_enabled: bool) {
     // Background panel
     commands.spawn((
         Node {
@@ -983,7 +2067,7 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &GameSettings) {
         
         // Instructions
         parent.spawn((
-            Text::new("Keyboard Shortcuts:\n\nCtrl+S: Save Game\nCtrl+L: Load Game\nCtrl+N: New Game\nCtrl+P: Random Ship Placement\nS: Toggle Sound\n\nESC: Close Settings"),
+            Text::new("Keyboard Shortcuts:\n\nCtrl+S: Save Game\nCtrl+L: Load Game\nCtrl+N: New Game\nCtrl+P: Random Ship Placement\nCtrl+O: Connect to Server\nCtrl+A: Toggle AI Opponent\nCtrl+H: Toggle Hotseat Mode\nS: Toggle Sound\nE: Toggle Particle Effects\nV: Cycle Board Skin\n\nESC: Close Settings"),
             TextFont {
                 font_size: 16.0,
                 ..default()
@@ -1105,18 +2189,181 @@ fn spawn_settings_menu(commands: &mut Commands, settings: &GameSettings) {
         ));
         
         parent.spawn((
-            Text::new("Press 'S' to toggle sound on/off"),
+            Text::new("Press 'S' to toggle sound on/off\nPress '[' / ']' to adjust volume"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+
+        // Music volume
+        parent.spawn((
+            Text::new(format!("\nMusic Volume: {}%", (settings.music_volume * 100.0) as i32)),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press '-' / '=' to adjust music volume"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+
+        // Particle effects
+        parent.spawn((
+            Text::new(if settings.effects_enabled {
+                "\nEffects: ON"
+            } else {
+                "\nEffects: OFF"
+            }),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(if settings.effects_enabled {
+                Color::srgb(0.6, 1.0, 0.6)
+            } else {
+                Color::srgb(0.7, 0.7, 0.7)
+            }),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press 'E' to toggle hit/miss/sunk particle effects"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+
+        // Particle density
+        parent.spawn((
+            Text::new(format!("\nParticle Density: {}", settings.particle_density.name())),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press 'P' to cycle particle density (scale down for low-end hardware)"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+
+        // Board skin
+        parent.spawn((
+            Text::new(format!("\nBoard Skin: {}", settings.skin_pair.name())),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press 'V' to cycle board color skins"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+
+        // Hotseat mode
+        parent.spawn((
+            Text::new(if hotseat_enabled {
+                "\nHotseat Mode: ON"
+            } else {
+                "\nHotseat Mode: OFF"
+            }),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(if hotseat_enabled {
+                Color::srgb(0.6, 1.0, 0.6)
+            } else {
+                Color::srgb(0.7, 0.7, 0.7)
+            }),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press Ctrl+H to toggle local two-player pass-and-play"),
             TextFont {
                 font_size: 10.0,
                 ..default()
             },
             TextColor(Color::srgb(0.5, 0.5, 0.5)),
         ));
+
+        // Lifetime stats
+        parent.spawn((
+            Text::new("\nLifetime Stats:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new(format!(
+                "Record: {}W - {}L | Accuracy: {:.0}% ({} hits / {} misses)",
+                settings.games_won,
+                settings.games_lost,
+                settings.accuracy_percent(),
+                settings.total_hits,
+                settings.total_misses,
+            )),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        ));
     });
 }
 
-fn despawn_settings_menu(_commands: &mut Commands) {
-    // Will be handled by a separate system
+fn despawn_settings_menu(commands: &mut Commands, query: &Query<Entity, With<SettingsMenu>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
 }
 
 fn handle_settings_menu(
@@ -1131,30 +2378,156 @@ fn handle_settings_menu(
     }
 }
 
+fn spawn_match_over_menu(
+    commands: &mut Commands,
+    game_state: &GameState,
+    settings: &GameSettings,
+    hotseat: &HotseatState,
+    winner: Winner,
+) {
+    let (headline, headline_color) = match winner {
+        Winner::Player => ("Victory!", Color::srgb(0.6, 1.0, 0.6)),
+        Winner::Opponent => ("Defeat", Color::srgb(1.0, 0.6, 0.6)),
+    };
+
+    let (player_remaining, player_total) = player_ships_remaining(game_state);
+    let (opponent_remaining, opponent_total) = if hotseat.enabled {
+        opponent_ships_remaining_exact(game_state)
+    } else {
+        opponent_ships_remaining(&game_state.opponent_board)
+    };
+
+    commands.spawn((
+        Node {
+            width: Val::Px(400.0),
+            height: Val::Px(300.0),
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Percent(50.0),
+            margin: UiRect::all(Val::Px(-150.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(20.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.95)),
+        MatchOverMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(headline),
+            TextFont {
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(headline_color),
+            Node {
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new(format!(
+                "Your fleet: {}/{} ships afloat\nEnemy fleet: {}/{} ships afloat",
+                player_remaining,
+                player_total,
+                opponent_remaining,
+                opponent_total,
+            )),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            Node {
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new(format!(
+                "Record: {}W - {}L | Accuracy: {:.0}%",
+                settings.games_won,
+                settings.games_lost,
+                settings.accuracy_percent(),
+            )),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.8, 1.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(15.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn((
+            Text::new("Press 'R' to play again"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+/// Spawns the match-over overlay the moment `placement_mode` becomes
+/// `GameOver`, and despawns it once "play again" returns to `Playing`.
+fn handle_match_over_menu(
+    game_state: Res<GameState>,
+    settings: Res<GameSettings>,
+    hotseat: Res<HotseatState>,
+    query: Query<Entity, With<MatchOverMenu>>,
+    mut commands: Commands,
+) {
+    match game_state.placement_mode {
+        PlacementMode::GameOver(winner) => {
+            if query.is_empty() {
+                spawn_match_over_menu(&mut commands, &game_state, &settings, &hotseat, winner);
+            }
+        }
+        _ => {
+            for entity in query.iter() {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
 fn show_ship_preview(
     game_state: Res<GameState>,
+    hotseat: Res<HotseatState>,
     mut query: Query<(&Cell, &mut Sprite)>,
 ) {
     if let PlacementMode::PlacingShip(ship_type, is_horizontal) = game_state.placement_mode {
         let size = ship_type.size();
-        let can_place = can_place_ship(&game_state.player_board, game_state.selected_x, game_state.selected_y, size, is_horizontal);
-        
+        let targets_player_board = hotseat::placement_targets_player_board(&hotseat);
+        let board = if targets_player_board {
+            &game_state.player_board
+        } else {
+            &game_state.opponent_board
+        };
+        let can_place = can_place_ship(board, game_state.selected_x, game_state.selected_y, size, is_horizontal);
+
         for (cell, mut sprite) in query.iter_mut() {
-            if !cell.is_player_board {
+            if cell.is_player_board != targets_player_board {
                 continue;
             }
-            
+
             let is_preview = if is_horizontal {
-                cell.y == game_state.selected_y && 
-                cell.x >= game_state.selected_x && 
+                cell.y == game_state.selected_y &&
+                cell.x >= game_state.selected_x &&
                 cell.x < game_state.selected_x + size
             } else {
-                cell.x == game_state.selected_x && 
-                cell.y >= game_state.selected_y && 
+                cell.x == game_state.selected_x &&
+                cell.y >= game_state.selected_y &&
                 cell.y < game_state.selected_y + size
             };
-            
-            if is_preview && game_state.player_board[cell.y][cell.x] == CellState::Empty {
+
+            if is_preview && board[cell.y][cell.x] == CellState::Empty {
                 sprite.color = if can_place {
                     Color::srgba(0.0, 1.0, 0.0, 0.5)
                 } else {
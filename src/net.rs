@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::events::{BoardSide, GameOver, ShotResolved, ShotResult, Winner};
+use crate::{GameState, PlacedShip, ShipType};
+
+/// A single parsed line of the antares text protocol.
+#[derive(Debug, Clone)]
+enum ServerMessage {
+    Id(u32),
+    Queued,
+    Opponent(String),
+    Wait,
+    Play,
+    ShotHit,
+    ShotMiss,
+    IncomingHit(usize, usize),
+    IncomingMiss(usize, usize),
+    Win,
+    Lose,
+    Unknown(String),
+}
+
+fn parse_message(line: &str) -> ServerMessage {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    match tokens.as_slice() {
+        ["id", n] => n
+            .parse()
+            .map(ServerMessage::Id)
+            .unwrap_or_else(|_| ServerMessage::Unknown(line.to_string())),
+        ["queued"] => ServerMessage::Queued,
+        ["oid", name, ..] => ServerMessage::Opponent(name.to_string()),
+        ["wait"] => ServerMessage::Wait,
+        ["play"] => ServerMessage::Play,
+        // One argument: the result of our own last shot, applied to opponent_board.
+        ["hit", _] => ServerMessage::ShotHit,
+        ["miss", _] => ServerMessage::ShotMiss,
+        // Two arguments: the opponent's shot against us, applied to player_board.
+        ["hit", x, y] => match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => ServerMessage::IncomingHit(x, y),
+            _ => ServerMessage::Unknown(line.to_string()),
+        },
+        ["miss", x, y] => match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => ServerMessage::IncomingMiss(x, y),
+            _ => ServerMessage::Unknown(line.to_string()),
+        },
+        ["win"] => ServerMessage::Win,
+        ["lose"] => ServerMessage::Lose,
+        _ => ServerMessage::Unknown(line.to_string()),
+    }
+}
+
+fn ship_type_token(ship_type: ShipType) -> &'static str {
+    match ship_type {
+        ShipType::Carrier => "carrier",
+        ShipType::Battleship => "battleship",
+        ShipType::Cruiser => "cruiser",
+        ShipType::Submarine => "submarine",
+        ShipType::Destroyer => "destroyer",
+    }
+}
+
+/// Connection to a remote Battleship server speaking the antares line protocol.
+#[derive(Resource, Default)]
+pub struct NetworkClient {
+    pub connected: bool,
+    pub player_id: Option<u32>,
+    pub opponent_name: Option<String>,
+    pub my_turn: bool,
+    last_shot: Option<(usize, usize)>,
+    sender: Option<Sender<String>>,
+    receiver: Option<Mutex<Receiver<ServerMessage>>>,
+}
+
+impl NetworkClient {
+    pub fn connect(&mut self, addr: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let writer_stream = TcpStream::connect(addr)?;
+        let reader_stream = writer_stream.try_clone()?;
+
+        let (msg_tx, msg_rx) = channel();
+        let (line_tx, line_rx): (Sender<String>, Receiver<String>) = channel();
+
+        thread::spawn(move || {
+            let mut writer = writer_stream;
+            for line in line_rx {
+                if writer.write_all(format!("{}\n", line).as_bytes()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            let reader = BufReader::new(reader_stream);
+            for line in reader.lines() {
+                let Ok(text) = line else { break };
+                if msg_tx.send(parse_message(&text)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.sender = Some(line_tx);
+        self.receiver = Some(Mutex::new(msg_rx));
+        self.connected = true;
+        self.player_id = None;
+        self.opponent_name = None;
+        self.my_turn = false;
+        Ok(())
+    }
+
+    fn send_line(&self, line: String) {
+        if let Some(ref sender) = self.sender {
+            let _ = sender.send(line);
+        }
+    }
+
+    pub fn send_layout(&self, ships: &[PlacedShip]) {
+        let mut tokens = vec!["layout".to_string()];
+        for ship in ships {
+            tokens.push(format!(
+                "{} {} {} {}",
+                ship_type_token(ship.ship_type),
+                ship.x,
+                ship.y,
+                if ship.is_horizontal { "h" } else { "v" }
+            ));
+        }
+        self.send_line(tokens.join(" "));
+    }
+
+    pub fn send_shot(&mut self, x: usize, y: usize) {
+        self.last_shot = Some((x, y));
+        self.send_line(format!("shot {} {}", x, y));
+    }
+}
+
+/// Drains queued `ServerMessage`s and turns shot results into `ShotResolved`
+/// events, the same pipeline the keyboard handlers feed.
+pub fn poll_network(
+    mut network: ResMut<NetworkClient>,
+    mut game_state: ResMut<GameState>,
+    mut shot_writer: EventWriter<ShotResolved>,
+    mut game_over_writer: EventWriter<GameOver>,
+) {
+    if !network.connected {
+        return;
+    }
+
+    let (messages, disconnected) = {
+        let Some(receiver) = network.receiver.as_ref() else {
+            return;
+        };
+        let receiver = receiver.lock().unwrap();
+        let mut out = Vec::new();
+        let mut disconnected = false;
+        loop {
+            match receiver.try_recv() {
+                Ok(msg) => out.push(msg),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    disconnected = true;
+                    break;
+                }
+            }
+        }
+        (out, disconnected)
+    };
+    // The `MutexGuard` borrowed from `network.receiver` above has to be
+    // dropped before `network` can be mutated again - `ResMut` can't
+    // split-borrow the way a plain struct field access can.
+    if disconnected {
+        network.connected = false;
+    }
+
+    for message in messages {
+        match message {
+            ServerMessage::Id(id) => {
+                println!("Connected to server as player {}", id);
+                network.player_id = Some(id);
+                network.send_layout(&game_state.ship_positions);
+            }
+            ServerMessage::Queued => println!("Waiting for an opponent..."),
+            ServerMessage::Opponent(name) => {
+                println!("Matched against {}", name);
+                network.opponent_name = Some(name);
+            }
+            ServerMessage::Wait => network.my_turn = false,
+            ServerMessage::Play => network.my_turn = true,
+            ServerMessage::ShotHit => {
+                if let Some((x, y)) = network.last_shot {
+                    shot_writer.write(ShotResolved {
+                        board: BoardSide::Opponent,
+                        x,
+                        y,
+                        result: ShotResult::Hit,
+                    });
+                }
+            }
+            ServerMessage::ShotMiss => {
+                if let Some((x, y)) = network.last_shot {
+                    shot_writer.write(ShotResolved {
+                        board: BoardSide::Opponent,
+                        x,
+                        y,
+                        result: ShotResult::Miss,
+                    });
+                }
+            }
+            ServerMessage::IncomingHit(x, y) => {
+                if x < crate::GRID_SIZE && y < crate::GRID_SIZE {
+                    shot_writer.write(ShotResolved {
+                        board: BoardSide::Player,
+                        x,
+                        y,
+                        result: ShotResult::Hit,
+                    });
+                }
+            }
+            ServerMessage::IncomingMiss(x, y) => {
+                if x < crate::GRID_SIZE && y < crate::GRID_SIZE {
+                    shot_writer.write(ShotResolved {
+                        board: BoardSide::Player,
+                        x,
+                        y,
+                        result: ShotResult::Miss,
+                    });
+                }
+            }
+            ServerMessage::Win => {
+                println!("You win!");
+                game_over_writer.write(GameOver { winner: Winner::Player });
+            }
+            ServerMessage::Lose => {
+                println!("You lose.");
+                game_over_writer.write(GameOver { winner: Winner::Opponent });
+            }
+            ServerMessage::Unknown(line) => eprintln!("Unrecognized server message: {}", line),
+        }
+    }
+}
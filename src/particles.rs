@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+use bevy_hanabi::prelude::*;
+
+use crate::{GameSettings, ParticleDensity};
+
+/// Which cue a burst is standing in for, so `spawn_impact_effect` can pick
+/// the right handle out of `ImpactEffects` and the right lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImpactKind {
+    Hit,
+    Miss,
+    Sunk,
+}
+
+/// Particle counts and lifetimes per effect, so the burst can be scaled down
+/// on low-end hardware without touching the gradients/shapes themselves.
+const HIT_PARTICLE_COUNT: f32 = 40.0;
+const HIT_LIFETIME_SECS: f32 = 0.5;
+const MISS_PARTICLE_COUNT: f32 = 16.0;
+const MISS_LIFETIME_SECS: f32 = 0.35;
+const SUNK_PARTICLE_COUNT: f32 = 100.0;
+const SUNK_LIFETIME_SECS: f32 = 0.9;
+
+/// `ParticleDensity` tiers, in the order `ImpactEffects` stores their handles.
+const DENSITY_TIERS: [ParticleDensity; 3] = [ParticleDensity::Full, ParticleDensity::Half, ParticleDensity::Low];
+
+fn density_index(density: ParticleDensity) -> usize {
+    DENSITY_TIERS.iter().position(|&tier| tier == density).unwrap_or(0)
+}
+
+/// The three GPU particle effects used for shot feedback, built once at
+/// startup and reused by every burst. Each kind carries one handle per
+/// `ParticleDensity` tier, built with a scaled-down particle count, so
+/// `spawn_impact_effect` can pick a cheaper burst on low-end hardware
+/// without rebuilding assets at runtime.
+#[derive(Resource)]
+pub struct ImpactEffects {
+    hit: [Handle<EffectAsset>; 3],
+    miss: [Handle<EffectAsset>; 3],
+    sunk: [Handle<EffectAsset>; 3],
+}
+
+fn build_burst_effect(name: &str, particle_count: f32, lifetime_secs: f32, speed: f32, size: f32, mut gradient: Gradient<Vec4>) -> EffectAsset {
+    gradient.set_ease(EaseFunction::Linear);
+
+    let mut module = Module::default();
+
+    let init_pos = SetPositionCircleModifier {
+        center: module.lit(Vec3::ZERO),
+        axis: module.lit(Vec3::Z),
+        radius: module.lit(1.0),
+        dimension: ShapeDimension::Volume,
+    };
+
+    let init_vel = SetVelocityCircleModifier {
+        center: module.lit(Vec3::ZERO),
+        axis: module.lit(Vec3::Z),
+        speed: module.lit(speed),
+    };
+
+    let init_lifetime = SetAttributeModifier::new(Attribute::LIFETIME, module.lit(lifetime_secs));
+
+    let size_modifier = SetSizeModifier {
+        size: Vec3::splat(size).into(),
+    };
+
+    let color_modifier = ColorOverLifetimeModifier { gradient };
+
+    EffectAsset::new(vec![particle_count as u32], Spawner::once(particle_count.into(), true), module)
+        .with_name(name)
+        .init(init_pos)
+        .init(init_vel)
+        .init(init_lifetime)
+        .render(size_modifier)
+        .render(color_modifier)
+}
+
+fn hit_gradient() -> Gradient<Vec4> {
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, Vec4::new(1.0, 0.5, 0.0, 1.0));
+    gradient.add_key(1.0, Vec4::new(1.0, 0.1, 0.0, 0.0));
+    gradient
+}
+
+fn miss_gradient() -> Gradient<Vec4> {
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, Vec4::new(0.3, 0.6, 1.0, 1.0));
+    gradient.add_key(1.0, Vec4::new(0.3, 0.6, 1.0, 0.0));
+    gradient
+}
+
+fn sunk_gradient() -> Gradient<Vec4> {
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, Vec4::new(1.0, 0.6, 0.0, 1.0));
+    gradient.add_key(0.5, Vec4::new(1.0, 0.2, 0.0, 0.8));
+    gradient.add_key(1.0, Vec4::new(0.2, 0.2, 0.2, 0.0));
+    gradient
+}
+
+/// Registers the hit/miss/sunk `EffectAsset`s with `Assets<EffectAsset>`, one
+/// per `ParticleDensity` tier, and stashes the handles in `ImpactEffects` for
+/// `spawn_impact_effect` to reuse.
+pub fn setup_impact_effects(mut commands: Commands, mut effects: ResMut<Assets<EffectAsset>>) {
+    let hit = DENSITY_TIERS.map(|tier| {
+        effects.add(build_burst_effect(
+            "hit-spray",
+            HIT_PARTICLE_COUNT * tier.scale(),
+            HIT_LIFETIME_SECS,
+            70.0,
+            4.0,
+            hit_gradient(),
+        ))
+    });
+    let miss = DENSITY_TIERS.map(|tier| {
+        effects.add(build_burst_effect(
+            "miss-splash",
+            MISS_PARTICLE_COUNT * tier.scale(),
+            MISS_LIFETIME_SECS,
+            35.0,
+            3.0,
+            miss_gradient(),
+        ))
+    });
+    let sunk = DENSITY_TIERS.map(|tier| {
+        effects.add(build_burst_effect(
+            "sunk-burst",
+            SUNK_PARTICLE_COUNT * tier.scale(),
+            SUNK_LIFETIME_SECS,
+            120.0,
+            6.0,
+            sunk_gradient(),
+        ))
+    });
+
+    commands.insert_resource(ImpactEffects { hit, miss, sunk });
+}
+
+/// Marks a spawned `ParticleEffect` burst entity for cleanup once its
+/// one-shot spawner has had time to finish emitting and every particle has
+/// faded out - `Spawner::once` only fires once, it doesn't despawn the
+/// entity afterward, so without this every hit/miss/sunk would leak one
+/// entity for the rest of the match.
+#[derive(Component)]
+struct GpuBurstLifetime(Timer);
+
+/// Spawns a one-shot GPU particle burst at `origin`, or does nothing if the
+/// player has turned effects off in the settings menu.
+pub fn spawn_impact_effect(
+    commands: &mut Commands,
+    impact_effects: &ImpactEffects,
+    settings: &GameSettings,
+    kind: ImpactKind,
+    origin: Vec2,
+) {
+    if !settings.effects_enabled {
+        return;
+    }
+
+    let tier = density_index(settings.particle_density);
+    let handle = match kind {
+        ImpactKind::Hit => impact_effects.hit[tier].clone(),
+        ImpactKind::Miss => impact_effects.miss[tier].clone(),
+        ImpactKind::Sunk => impact_effects.sunk[tier].clone(),
+    };
+    let lifetime_secs = match kind {
+        ImpactKind::Hit => HIT_LIFETIME_SECS,
+        ImpactKind::Miss => MISS_LIFETIME_SECS,
+        ImpactKind::Sunk => SUNK_LIFETIME_SECS,
+    };
+
+    commands.spawn((
+        ParticleEffect::new(handle),
+        Transform::from_translation(origin.extend(2.0)),
+        GpuBurstLifetime(Timer::from_seconds(lifetime_secs, TimerMode::Once)),
+    ));
+}
+
+/// Despawns `ParticleEffect` burst entities once their lifetime timer runs
+/// out, same cleanup `update_particles` does for the CPU fallback.
+pub fn despawn_finished_bursts(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity, &mut GpuBurstLifetime)>) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        lifetime.0.tick(time.delta());
+        if lifetime.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// A single CPU-simulated impact particle: drifts along `velocity` until
+/// `lifetime` runs out, then despawns. Only used as a fallback for when the
+/// `bevy_hanabi` GPU pipeline isn't available - `spawn_impact_effect` above
+/// (gated on `ImpactEffects` existing) is the normal path.
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub lifetime: Timer,
+}
+
+fn cpu_impact_color(kind: ImpactKind) -> Color {
+    match kind {
+        ImpactKind::Hit => Color::srgb(1.0, 0.5, 0.0),
+        ImpactKind::Miss => Color::srgb(0.3, 0.6, 1.0),
+        ImpactKind::Sunk => Color::srgb(1.0, 0.6, 0.0),
+    }
+}
+
+fn cpu_impact_params(kind: ImpactKind) -> (f32, f32, f32) {
+    match kind {
+        ImpactKind::Hit => (HIT_PARTICLE_COUNT, 70.0, HIT_LIFETIME_SECS),
+        ImpactKind::Miss => (MISS_PARTICLE_COUNT, 35.0, MISS_LIFETIME_SECS),
+        ImpactKind::Sunk => (SUNK_PARTICLE_COUNT, 120.0, SUNK_LIFETIME_SECS),
+    }
+}
+
+/// Spawns a one-shot burst of CPU-simulated `Particle` sprites at `origin`,
+/// scaled by `settings.particle_density` the same way the GPU bursts are.
+/// Used in place of `spawn_impact_effect` wherever the `ImpactEffects`
+/// resource hasn't been inserted (e.g. a headless run with the `bevy_hanabi`
+/// plugin never registered).
+pub fn spawn_cpu_impact_particles(commands: &mut Commands, settings: &GameSettings, kind: ImpactKind, origin: Vec2) {
+    if !settings.effects_enabled {
+        return;
+    }
+
+    let (base_count, speed, lifetime_secs) = cpu_impact_params(kind);
+    let count = ((base_count * settings.particle_density.scale()).round() as u32).max(1);
+    let color = cpu_impact_color(kind);
+
+    for i in 0..count {
+        let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+        commands.spawn((
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(3.0)),
+                ..default()
+            },
+            Transform::from_translation(origin.extend(2.0)),
+            Particle {
+                velocity,
+                lifetime: Timer::from_seconds(lifetime_secs, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+/// Advances every `Particle` along its velocity and despawns it once its
+/// lifetime timer finishes.
+pub fn update_particles(mut commands: Commands, time: Res<Time>, mut query: Query<(Entity, &mut Transform, &mut Particle)>) {
+    for (entity, mut transform, mut particle) in query.iter_mut() {
+        transform.translation += (particle.velocity * time.delta_secs()).extend(0.0);
+        particle.lifetime.tick(time.delta());
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}